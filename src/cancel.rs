@@ -0,0 +1,132 @@
+//! Cooperative cancellation of long running solvers.
+//!
+//! A [`Token`] carries an optional deadline and can be cancelled from any
+//! thread. [`run`] makes a token current on this thread for the duration of a
+//! closure, and the long loops of the solvers call [`checkpoint`], which
+//! unwinds straight back to `run` once the token is cancelled or its deadline
+//! has passed. Solvers therefore keep their plain signatures, and a solver
+//! called outside of `run` is never interrupted.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/**
+ * Why a solver stopped before finishing.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Cancelled {
+    /// The token's deadline passed.
+    TimedOut,
+    /// [`Token::cancel`] was called.
+    Cancelled,
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Cancelled::TimedOut => write!(f, "timed out"),
+            Cancelled::Cancelled => write!(f, "cancelled"),
+        }
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+/**
+ * A cancellation flag shared by all clones of the token, plus an optional
+ * deadline.
+ */
+#[derive(Clone, Debug, Default)]
+pub struct Token {
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    /**
+     * A token that is only cancelled explicitly.
+     */
+    pub fn new() -> Token {
+        Token::default()
+    }
+
+    pub fn with_deadline(deadline: Instant) -> Token {
+        Token {
+            cancelled: Arc::default(),
+            deadline: Some(deadline),
+        }
+    }
+
+    /**
+     * A token whose deadline is `timeout` from now.
+     */
+    pub fn with_timeout(timeout: Duration) -> Token {
+        Token::with_deadline(Instant::now() + timeout)
+    }
+
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /**
+     * Cancels this token and all of its clones.
+     */
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /**
+     * Returns why work under this token should stop, if it should.
+     */
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Err(Cancelled::Cancelled);
+        }
+        match self.deadline {
+            Some(deadline) if Instant::now() >= deadline => Err(Cancelled::TimedOut),
+            _ => Ok(()),
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/**
+ * Runs `f` with `token` as this thread's current token and returns its result,
+ * or why it was stopped at a [`checkpoint`].
+ *
+ * Any other panic in `f` is passed on to the caller unchanged.
+ */
+pub fn run<T>(token: &Token, f: impl FnOnce() -> T) -> Result<T, Cancelled> {
+    let previous = CURRENT.with(|current| current.replace(Some(token.clone())));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CURRENT.with(|current| *current.borrow_mut() = previous);
+
+    match result {
+        Ok(value) => Ok(value),
+        Err(payload) => match payload.downcast::<Cancelled>() {
+            Ok(reason) => Err(*reason),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    }
+}
+
+/**
+ * Stops the current solver if this thread's token was cancelled or has timed
+ * out. Does nothing outside of [`run`].
+ *
+ * Call this once per iteration of any loop that may run for long.
+ */
+pub fn checkpoint() {
+    let stop = CURRENT.with(|current| current.borrow().as_ref().map_or(Ok(()), Token::check));
+    if let Err(reason) = stop {
+        // resume_unwind skips the panic hook, nothing is printed
+        panic::resume_unwind(Box::new(reason));
+    }
+}
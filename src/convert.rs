@@ -0,0 +1,212 @@
+//! Structured forms of the puzzle inputs.
+//!
+//! Every day's parsed model can be written as JSON and read back, so that other
+//! tools can generate or inspect puzzle data. Models that are a list of records
+//! with the same fields, such as day 4's pairs, can also be written as CSV with
+//! one row per record. Nested fields become columns named by their path, e.g.
+//! `position.x`.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+/**
+ * The forms a day's input can take.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// The puzzle input as published.
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Format, String> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown input format '{}'", s)),
+        }
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Format::Text => write!(f, "text"),
+            Format::Json => write!(f, "json"),
+            Format::Csv => write!(f, "csv"),
+        }
+    }
+}
+
+/**
+ * Writes a model as JSON or CSV. Puzzle text is written by the day's own
+ * `to_text` instead.
+ */
+pub fn encode<T: Serialize>(model: &T, format: Format) -> Result<String, String> {
+    match format {
+        Format::Text => Err("puzzle text is not a structured format".to_string()),
+        Format::Json => serde_json::to_string_pretty(model)
+            .map(|json| json + "\n")
+            .map_err(|e| e.to_string()),
+        Format::Csv => {
+            let value = serde_json::to_value(model).map_err(|e| e.to_string())?;
+            to_csv(&value).map_err(|e| format!("cannot be written as CSV: {}", e))
+        }
+    }
+}
+
+/**
+ * Reads a model from JSON or CSV.
+ */
+pub fn decode<T: DeserializeOwned>(data: &str, format: Format) -> Result<T, String> {
+    match format {
+        Format::Text => Err("puzzle text is not a structured format".to_string()),
+        Format::Json => from_json(data).map_err(|e| format!("invalid JSON: {}", e)),
+        Format::Csv => {
+            let value = from_csv(data)?;
+            serde_json::from_value(value).map_err(|e| format!("invalid CSV: {}", e))
+        }
+    }
+}
+
+/**
+ * Reads JSON nested as deep as the model goes. A day 7 directory tree takes
+ * two levels per directory, so a deep one passes the limit serde_json keeps by
+ * default, and the stack grows as needed instead.
+ */
+fn from_json<T: DeserializeOwned>(data: &str) -> Result<T, serde_json::Error> {
+    let mut deserializer = serde_json::Deserializer::from_str(data);
+    deserializer.disable_recursion_limit();
+    let value = T::deserialize(serde_stacker::Deserializer::new(&mut deserializer))?;
+    deserializer.end()?;
+    Ok(value)
+}
+
+/**
+ * The column of a record that is a single value rather than a struct or list.
+ */
+const VALUE_COLUMN: &str = "value";
+
+/**
+ * Lists the leaves of a record as (column, cell), depth first.
+ */
+fn flatten(path: &str, value: &Value, cells: &mut Vec<(String, String)>) {
+    let child = |key: &str| {
+        if path.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}.{}", path, key)
+        }
+    };
+    match value {
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(&child(&i.to_string()), item, cells);
+            }
+        }
+        Value::Object(fields) => {
+            for (key, field) in fields {
+                flatten(&child(key), field, cells);
+            }
+        }
+        leaf => {
+            let column = if path.is_empty() { VALUE_COLUMN.to_string() } else { path.to_string() };
+            let cell = match leaf {
+                Value::String(s) => s.clone(),
+                Value::Null => String::new(),
+                other => other.to_string(),
+            };
+            cells.push((column, cell));
+        }
+    }
+}
+
+fn to_csv(value: &Value) -> Result<String, String> {
+    let records = value.as_array().ok_or("only a list of records has rows")?;
+
+    let mut writer = csv::Writer::from_writer(Vec::new());
+    let mut header: Option<Vec<String>> = None;
+    for (i, record) in records.iter().enumerate() {
+        let mut cells = Vec::new();
+        flatten("", record, &mut cells);
+        let (columns, row): (Vec<String>, Vec<String>) = cells.into_iter().unzip();
+        match &header {
+            None => {
+                writer.write_record(&columns).map_err(|e| e.to_string())?;
+                header = Some(columns);
+            }
+            Some(header) if *header != columns => {
+                return Err(format!("record {} has other fields than the first", i + 1));
+            }
+            Some(_) => {}
+        }
+        writer.write_record(&row).map_err(|e| e.to_string())?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+/**
+ * Stores `cell` at the path of its column, creating the lists and objects on
+ * the way. Numeric path segments are list indices.
+ */
+fn insert(target: &mut Value, path: &[&str], cell: Value) {
+    let Some((key, rest)) = path.split_first() else {
+        *target = cell;
+        return;
+    };
+    match key.parse::<usize>() {
+        Ok(index) => {
+            if !target.is_array() {
+                *target = Value::Array(Vec::new());
+            }
+            let items = target.as_array_mut().unwrap();
+            if items.len() <= index {
+                items.resize(index + 1, Value::Null);
+            }
+            insert(&mut items[index], rest, cell);
+        }
+        Err(_) => {
+            if !target.is_object() {
+                *target = Value::Object(Map::new());
+            }
+            let field = target.as_object_mut().unwrap().entry(key.to_string()).or_insert(Value::Null);
+            insert(field, rest, cell);
+        }
+    }
+}
+
+fn from_csv(data: &str) -> Result<Value, String> {
+    let mut reader = csv::Reader::from_reader(data.as_bytes());
+    let header = reader.headers().map_err(|e| format!("invalid CSV: {}", e))?.clone();
+
+    let mut records = Vec::new();
+    for row in reader.records() {
+        let row = row.map_err(|e| format!("invalid CSV: {}", e))?;
+        let mut record = Value::Null;
+        for (column, cell) in header.iter().zip(row.iter()) {
+            // numbers come back as numbers, everything else as text
+            let cell = match serde_json::from_str::<serde_json::Number>(cell) {
+                Ok(number) => Value::Number(number),
+                Err(_) => Value::String(cell.to_string()),
+            };
+            if column == VALUE_COLUMN {
+                insert(&mut record, &[], cell);
+            } else {
+                insert(&mut record, &column.split('.').collect::<Vec<&str>>(), cell);
+            }
+        }
+        records.push(record);
+    }
+    Ok(Value::Array(records))
+}
@@ -0,0 +1,124 @@
+//! Differential testing of Rust solutions against the original Python scripts.
+//!
+//! Both implementations are run on the same input and their answers compared.
+//! A failure on both sides is reported too, with both errors: the two sides
+//! fail in their own words, so there is no telling whether they failed for the
+//! same reason.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::registry;
+use crate::runner;
+
+/**
+ * Imports the script as a module and prints the answer of one part for
+ * `input.txt` in the working directory. numpy is only used to dump maps, so a
+ * minimal stand-in is provided when it isn't installed.
+ */
+const PYTHON_WRAPPER: &str = r#"
+import importlib, sys, types
+try:
+    import numpy
+except ImportError:
+    numpy = types.ModuleType("numpy")
+    def savetxt(fname, rows, fmt="%s", delimiter=" "):
+        with open(fname, "w") as f:
+            for row in rows:
+                f.write(delimiter.join(fmt % cell for cell in row) + "\n")
+    numpy.savetxt = savetxt
+    sys.modules["numpy"] = numpy
+sys.path.insert(0, sys.argv[1])
+module = importlib.import_module(sys.argv[2])
+print("ANSWER:", getattr(module, "part" + sys.argv[3])("input.txt"))
+"#;
+
+#[derive(Debug)]
+pub struct Disagreement {
+    pub part: u8,
+    pub input: String,
+    pub rust: Result<String, String>,
+    pub python: Result<String, String>,
+}
+
+/**
+ * Returns a fresh scratch directory for one Python run, so the files the
+ * scripts write next to their input don't collide.
+ */
+fn scratch_dir() -> std::io::Result<PathBuf> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let dir = std::env::temp_dir().join(format!(
+        "aoc-difftest-{}-{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+/**
+ * Runs one part of a Python script on the given input and returns its answer.
+ */
+pub fn run_python(script: &Path, part: u8, input: &str) -> Result<String, String> {
+    let script = script
+        .canonicalize()
+        .map_err(|e| format!("{}: {}", script.display(), e))?;
+    let module = script.file_stem().and_then(|s| s.to_str()).ok_or("invalid script name")?;
+    let script_dir = script.parent().ok_or("invalid script path")?;
+
+    let dir = scratch_dir().map_err(|e| e.to_string())?;
+    fs::write(dir.join("input.txt"), input).map_err(|e| e.to_string())?;
+    let output = Command::new("python3")
+        .arg("-c")
+        .arg(PYTHON_WRAPPER)
+        .arg(script_dir)
+        .arg(module)
+        .arg(part.to_string())
+        .current_dir(&dir)
+        .output();
+    let _ = fs::remove_dir_all(&dir);
+    let output = output.map_err(|e| format!("failed to start python3: {}", e))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    match stdout.lines().rev().find_map(|line| line.strip_prefix("ANSWER: ")) {
+        Some(answer) if output.status.success() => Ok(answer.trim().to_string()),
+        _ => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            Err(stderr.lines().last().unwrap_or("python failed").to_string())
+        }
+    }
+}
+
+/**
+ * Runs the registered Rust solution on the given input.
+ */
+pub fn run_rust(year: u16, day: u8, part: u8, input: &str) -> Result<String, String> {
+    let solution = registry::find(year, day, part)
+        .ok_or_else(|| format!("{} day {} part {} is not implemented in Rust", year, day, part))?;
+    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
+    runner::run(solution, &lines).answer.map_err(|e| e.to_string())
+}
+
+/**
+ * Compares both parts of a day on one input and returns every part whose
+ * answers differ or that failed on either side.
+ */
+pub fn compare(year: u16, day: u8, script: &Path, input: &str) -> Vec<Disagreement> {
+    let mut disagreements = Vec::new();
+    for part in [1, 2] {
+        let rust = run_rust(year, day, part, input);
+        let python = run_python(script, part, input);
+        let agree = matches!((&rust, &python), (Ok(r), Ok(p)) if r == p);
+        if !agree {
+            disagreements.push(Disagreement {
+                part,
+                input: input.to_string(),
+                rust,
+                python,
+            });
+        }
+    }
+    disagreements
+}
@@ -0,0 +1,70 @@
+//! Seeded random puzzle inputs, used to exercise the solvers beyond the
+//! example and personal inputs.
+//!
+//! Every generator is deterministic for a given seed and size, and produces
+//! inputs in the same format as the real puzzle.
+
+use std::str::FromStr;
+
+use crate::util::rng::Rng;
+
+mod y2022;
+
+/**
+ * How large a generated input should be, as a factor over the small size.
+ *
+ * Line based inputs grow linearly with the factor, grids grow so that their
+ * area does.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Size(pub u32);
+
+impl Size {
+    pub const SMALL: Size = Size(1);
+    pub const MEDIUM: Size = Size(10);
+    pub const LARGE: Size = Size(100);
+
+    /**
+     * Scales a count of lines, items, elves and the like.
+     */
+    pub fn count(self, base: i64) -> i64 {
+        base * self.0 as i64
+    }
+
+    /**
+     * Scales the side of a grid so that its area grows with the factor.
+     */
+    pub fn side(self, base: i64) -> i64 {
+        (base as f64 * (self.0 as f64).sqrt()).round() as i64
+    }
+}
+
+impl FromStr for Size {
+    type Err = String;
+
+    /**
+     * Accepts `small`, `medium`, `large` or a positive factor such as `250`.
+     */
+    fn from_str(s: &str) -> Result<Size, String> {
+        match s {
+            "small" => Ok(Size::SMALL),
+            "medium" => Ok(Size::MEDIUM),
+            "large" => Ok(Size::LARGE),
+            _ => match s.parse() {
+                Ok(factor) if factor > 0 => Ok(Size(factor)),
+                _ => Err(format!("invalid size '{}'", s)),
+            },
+        }
+    }
+}
+
+/**
+ * Generates a random, structurally valid input for the given day, or `None`
+ * if there is no generator for it.
+ */
+pub fn generate(year: u16, day: u8, rng: &mut Rng, size: Size) -> Option<String> {
+    match year {
+        2022 => y2022::generate(day, rng, size),
+        _ => None,
+    }
+}
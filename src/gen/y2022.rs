@@ -0,0 +1,491 @@
+use crate::gen::Size;
+use crate::util::rng::Rng;
+
+pub fn generate(day: u8, rng: &mut Rng, size: Size) -> Option<String> {
+    let input = match day {
+        1 => day01(rng, size),
+        2 => day02(rng, size),
+        3 => day03(rng, size),
+        4 => day04(rng, size),
+        5 => day05(rng, size),
+        6 => day06(rng, size),
+        7 => day07(rng, size),
+        8 => day08(rng, size),
+        9 => day09(rng, size),
+        10 => day10(rng, size),
+        11 => day11(rng, size),
+        12 => day12(rng, size),
+        13 => day13(rng, size),
+        14 => day14(rng, size),
+        15 => day15(rng, size),
+        _ => return None,
+    };
+    Some(input)
+}
+
+/**
+ * Calorie counts, one elf per blank-line separated group.
+ */
+fn day01(rng: &mut Rng, size: Size) -> String {
+    let mut elves = Vec::new();
+    for _ in 0..size.count(100) {
+        let items: Vec<String> = (0..rng.range(1, 15))
+            .map(|_| rng.range(1000, 70000).to_string())
+            .collect();
+        elves.push(items.join("\n"));
+    }
+    elves.join("\n\n") + "\n"
+}
+
+/**
+ * Rock paper scissors rounds such as `A Y`.
+ */
+fn day02(rng: &mut Rng, size: Size) -> String {
+    let mut lines = String::new();
+    for _ in 0..size.count(1000) {
+        let opponent = rng.pick(&['A', 'B', 'C']);
+        let me = rng.pick(&['X', 'Y', 'Z']);
+        lines.push_str(&format!("{} {}\n", opponent, me));
+    }
+    lines
+}
+
+/**
+ * Rucksacks in groups of three.
+ *
+ * The two halves of every rucksack share exactly one item and the three
+ * rucksacks of a group share exactly one badge. Apart from the badge, a
+ * rucksack only holds items whose index is not congruent to its position in
+ * the group modulo 3, so no other item can be common to the whole group.
+ */
+fn day03(rng: &mut Rng, size: Size) -> String {
+    let items: Vec<char> = ('a'..='z').chain('A'..='Z').collect();
+    let mut lines = String::new();
+
+    for _ in 0..size.count(100) {
+        let badge = *rng.pick(&items);
+        for k in 0..3 {
+            let mut allowed: Vec<char> = (0..items.len())
+                .filter(|i| i % 3 != k && items[*i] != badge)
+                .map(|i| items[i])
+                .collect();
+            rng.shuffle(&mut allowed);
+
+            // one shared item, the rest split between the two halves
+            let shared = allowed.pop().unwrap();
+            let (first_pool, second_pool) = allowed.split_at(allowed.len() / 2);
+
+            let half = rng.range(2, 16) as usize;
+            let mut first = vec![shared, badge];
+            let mut second = vec![shared];
+            while first.len() < half {
+                first.push(*rng.pick(first_pool));
+            }
+            while second.len() < half {
+                second.push(*rng.pick(second_pool));
+            }
+            rng.shuffle(&mut first);
+            rng.shuffle(&mut second);
+
+            lines.extend(first);
+            lines.extend(second);
+            lines.push('\n');
+        }
+    }
+    lines
+}
+
+/**
+ * Pairs of section ranges such as `2-4,6-8`.
+ */
+fn day04(rng: &mut Rng, size: Size) -> String {
+    let mut lines = String::new();
+    for _ in 0..size.count(1000) {
+        let (a, b) = (rng.range(1, 99), rng.range(1, 99));
+        let (c, d) = (rng.range(1, 99), rng.range(1, 99));
+        lines.push_str(&format!("{}-{},{}-{}\n", a.min(b), a.max(b), c.min(d), c.max(d)));
+    }
+    lines
+}
+
+/**
+ * A crate drawing with more than nine stacks followed by moves.
+ *
+ * Moves never empty a stack, so every stack has a top crate at the end.
+ */
+fn day05(rng: &mut Rng, size: Size) -> String {
+    let num_stacks = rng.range(10, 15) as usize;
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    for _ in 0..num_stacks {
+        let height = rng.range(1, size.count(8)) as usize;
+        stacks.push((0..height).map(|_| (b'A' + rng.range(0, 25) as u8) as char).collect());
+    }
+
+    // the drawing, from the highest level down to the stack numbers
+    let mut lines = String::new();
+    let max_height = stacks.iter().map(|s| s.len()).max().unwrap();
+    for level in (0..max_height).rev() {
+        let row: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        lines.push_str(&row.join(" "));
+        lines.push('\n');
+    }
+    let labels: Vec<String> = (1..=num_stacks).map(|i| format!(" {:<2}", i)).collect();
+    lines.push_str(&labels.join(" "));
+    lines.push_str("\n\n");
+
+    let mut heights: Vec<i64> = stacks.iter().map(|s| s.len() as i64).collect();
+    for _ in 0..size.count(200) {
+        let src = rng.range(0, num_stacks as i64 - 1) as usize;
+        let dst = (src + rng.range(1, num_stacks as i64 - 1) as usize) % num_stacks;
+        if heights[src] < 2 {
+            continue;
+        }
+        let num = rng.range(1, heights[src] - 1);
+        heights[src] -= num;
+        heights[dst] += num;
+        lines.push_str(&format!("move {} from {} to {}\n", num, src + 1, dst + 1));
+    }
+    lines
+}
+
+/**
+ * A datastream whose start-of-packet and start-of-message markers only show
+ * up after a long stretch drawn from three letters.
+ */
+fn day06(rng: &mut Rng, size: Size) -> String {
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    rng.shuffle(&mut letters);
+
+    let mut stream: String = (0..size.count(1000)).map(|_| *rng.pick(&letters[..3])).collect();
+    stream.extend(&letters[..14]);
+    stream.extend((0..size.count(1000)).map(|_| *rng.pick(&letters)));
+    stream + "\n"
+}
+
+/**
+ * The most bytes of files in a day 7 transcript, leaving part 2 of the
+ * puzzle enough room on its 70000000 byte disk.
+ */
+const DAY07_TOTAL_SIZE: i64 = 30_000_000;
+
+/**
+ * How deep day 7 directories nest, so that the budget is spread out instead
+ * of going into a single chain.
+ */
+const DAY07_MAX_DEPTH: usize = 12;
+
+/**
+ * A terminal transcript that explores a random directory tree depth first,
+ * listing every directory exactly once.
+ */
+fn day07(rng: &mut Rng, size: Size) -> String {
+    fn explore(rng: &mut Rng, lines: &mut Vec<String>, budget: &mut i64, max_file: i64, depth: usize) {
+        lines.push("$ ls".to_string());
+
+        let mut dirs = Vec::new();
+        let mut entries = Vec::new();
+        for i in 0..rng.range(1, 6) {
+            if *budget > 0 && depth < DAY07_MAX_DEPTH && rng.chance(0.5) {
+                *budget -= 1;
+                let name = format!("d{}{}", (b'a' + rng.range(0, 25) as u8) as char, i);
+                entries.push(format!("dir {}", name));
+                dirs.push(name);
+            } else {
+                let name = format!("f{}.{}", i, rng.pick(&["txt", "dat", "log", "bin"]));
+                entries.push(format!("{} {}", rng.range(1, max_file), name));
+            }
+        }
+        rng.shuffle(&mut entries);
+        lines.extend(entries);
+
+        for dir in dirs {
+            lines.push(format!("$ cd {}", dir));
+            explore(rng, lines, budget, max_file, depth + 1);
+            lines.push("$ cd ..".to_string());
+        }
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    let mut budget = size.count(30);
+    // every directory, the root included, holds at most five files
+    let max_file = (DAY07_TOTAL_SIZE / (5 * (budget + 1))).clamp(1, 300000);
+    explore(rng, &mut lines, &mut budget, max_file, 0);
+    lines.join("\n") + "\n"
+}
+
+/**
+ * A square forest of tree heights.
+ */
+fn day08(rng: &mut Rng, size: Size) -> String {
+    let side = size.side(30);
+    let mut lines = String::new();
+    for _ in 0..side {
+        lines.extend((0..side).map(|_| (b'0' + rng.range(0, 9) as u8) as char));
+        lines.push('\n');
+    }
+    lines
+}
+
+/**
+ * Rope head motions such as `R 4`.
+ */
+fn day09(rng: &mut Rng, size: Size) -> String {
+    let mut lines = String::new();
+    for _ in 0..size.count(500) {
+        let direction = rng.pick(&['U', 'D', 'L', 'R']);
+        lines.push_str(&format!("{} {}\n", direction, rng.range(1, 20)));
+    }
+    lines
+}
+
+/**
+ * A CPU program of `noop` and `addx` that runs for at least 240 cycles
+ * (one full CRT frame) per size step.
+ */
+fn day10(rng: &mut Rng, size: Size) -> String {
+    let mut lines = String::new();
+    let mut cycles = 0;
+    let mut reg = 1;
+    while cycles < size.count(240) {
+        if rng.chance(0.3) {
+            lines.push_str("noop\n");
+            cycles += 1;
+        } else {
+            // keep the sprite roughly on screen
+            let operand = (rng.range(-10, 10)).clamp(-reg, 39 - reg);
+            reg += operand;
+            lines.push_str(&format!("addx {}\n", operand));
+            cycles += 2;
+        }
+    }
+    lines
+}
+
+/**
+ * Notes on monkeys that throw items to each other.
+ *
+ * The divisors are primes up to 23 so that part 2 can keep worry levels small
+ * with their product, and at most one monkey squares the worry level. Notes
+ * whose 20 rounds of part 1 would overflow an i64 are rerolled.
+ */
+fn day11(rng: &mut Rng, size: Size) -> String {
+    struct Monkey {
+        items: Vec<i64>,
+        // (multiplier, addend), `None` as the multiplier squares the worry level
+        operation: (Option<i64>, i64),
+        divisor: i64,
+        dst: (usize, usize),
+    }
+
+    let fits_part1 = |monkeys: &[Monkey]| -> bool {
+        let mut items: Vec<Vec<i64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+        for _ in 0..20 {
+            for (i, monkey) in monkeys.iter().enumerate() {
+                for item in std::mem::take(&mut items[i]) {
+                    let worry = match monkey.operation {
+                        (None, _) => item.checked_mul(item),
+                        (Some(factor), addend) => item.checked_mul(factor).and_then(|w| w.checked_add(addend)),
+                    };
+                    let Some(worry) = worry.map(|w| w / 3) else {
+                        return false;
+                    };
+                    let dst = if worry % monkey.divisor == 0 { monkey.dst.0 } else { monkey.dst.1 };
+                    items[dst].push(worry);
+                }
+            }
+        }
+        true
+    };
+
+    let monkeys = loop {
+        let mut divisors = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+        rng.shuffle(&mut divisors);
+        let num_monkeys = rng.range(2, 8) as usize;
+        let squarer = rng.range(0, num_monkeys as i64 - 1) as usize;
+
+        let mut monkeys = Vec::new();
+        for m in 0..num_monkeys {
+            let items = (0..rng.range(1, size.count(4))).map(|_| rng.range(50, 99)).collect();
+            let operation = if m == squarer {
+                (None, 0)
+            } else if rng.chance(0.5) {
+                (Some(rng.range(2, 19)), 0)
+            } else {
+                (Some(1), rng.range(1, 8))
+            };
+            let dst1 = (m + rng.range(1, num_monkeys as i64 - 1) as usize) % num_monkeys;
+            let mut dst2 = (m + rng.range(1, num_monkeys as i64 - 1) as usize) % num_monkeys;
+            if dst2 == dst1 && num_monkeys > 2 {
+                dst2 = (0..num_monkeys).find(|&d| d != m && d != dst1).unwrap();
+            }
+            monkeys.push(Monkey {
+                items,
+                operation,
+                divisor: divisors[m % divisors.len()],
+                dst: (dst1, dst2),
+            });
+        }
+
+        if fits_part1(&monkeys) {
+            break monkeys;
+        }
+    };
+
+    let mut notes = Vec::new();
+    for (m, monkey) in monkeys.iter().enumerate() {
+        let items: Vec<String> = monkey.items.iter().map(|i| i.to_string()).collect();
+        let operation = match monkey.operation {
+            (None, _) => "old * old".to_string(),
+            (Some(1), addend) => format!("old + {}", addend),
+            (Some(factor), _) => format!("old * {}", factor),
+        };
+        notes.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            m,
+            items.join(", "),
+            operation,
+            monkey.divisor,
+            monkey.dst.0,
+            monkey.dst.1
+        ));
+    }
+    notes.join("\n")
+}
+
+/**
+ * A heightmap with a guaranteed path from `S` on the left edge to `E` on the
+ * right edge.
+ *
+ * The path moves right or towards the row of `E` and climbs evenly from `a`
+ * to `z`, the rest of the map is random.
+ */
+fn day12(rng: &mut Rng, size: Size) -> String {
+    let width = size.side(40).max(26) as usize;
+    let height = size.side(20).max(2) as usize;
+    let mut map: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| rng.range(0, 25) as u8).collect())
+        .collect();
+
+    let start = (rng.range(0, height as i64 - 1) as usize, 0);
+    let end = (rng.range(0, height as i64 - 1) as usize, width - 1);
+
+    // a monotone lattice path from start to end
+    let mut path = vec![start];
+    let (mut row, mut col) = start;
+    while (row, col) != end {
+        if row != end.0 && (col == end.1 || rng.chance(0.3)) {
+            if row < end.0 {
+                row += 1;
+            } else {
+                row -= 1;
+            }
+        } else {
+            col += 1;
+        }
+        path.push((row, col));
+    }
+    for (k, &(row, col)) in path.iter().enumerate() {
+        map[row][col] = (25 * k / (path.len() - 1)) as u8;
+    }
+
+    let mut lines = String::new();
+    for (row, cells) in map.iter().enumerate() {
+        for (col, &cell) in cells.iter().enumerate() {
+            lines.push(match (row, col) {
+                p if p == start => 'S',
+                p if p == end => 'E',
+                _ => (b'a' + cell) as char,
+            });
+        }
+        lines.push('\n');
+    }
+    lines
+}
+
+/**
+ * A random list packet, nested at most `depth` lists deep.
+ */
+fn packet(rng: &mut Rng, depth: u32) -> String {
+    let mut items = Vec::new();
+    for _ in 0..rng.range(0, 4) {
+        if depth > 0 && rng.chance(0.4) {
+            items.push(packet(rng, depth - 1));
+        } else {
+            items.push(rng.range(0, 10).to_string());
+        }
+    }
+    format!("[{}]", items.join(","))
+}
+
+/**
+ * Pairs of packets separated by blank lines.
+ */
+fn day13(rng: &mut Rng, size: Size) -> String {
+    let mut pairs = Vec::new();
+    for _ in 0..rng.range(1, size.count(12)) {
+        pairs.push(format!("{}\n{}", packet(rng, 3), packet(rng, 3)));
+    }
+    pairs.join("\n\n") + "\n"
+}
+
+/**
+ * Axis-aligned rock paths below the sand source at x=500.
+ */
+fn day14(rng: &mut Rng, size: Size) -> String {
+    let mut lines = Vec::new();
+    for _ in 0..rng.range(1, size.count(8)) {
+        let mut x = rng.range(470, 530);
+        let mut y = rng.range(2, 40);
+        let mut corners = vec![format!("{},{}", x, y)];
+        for _ in 0..rng.range(1, 4) {
+            if rng.chance(0.5) {
+                x = (x + rng.range(-8, 8)).clamp(460, 540);
+            } else {
+                y = (y + rng.range(-8, 8)).clamp(2, 50);
+            }
+            corners.push(format!("{},{}", x, y));
+        }
+        lines.push(corners.join(" -> "));
+    }
+    lines.join("\n") + "\n"
+}
+
+/**
+ * Sensors in two clusters that both reach row 0 but leave a gap between them,
+ * so the distress beacon search ends on the first row, plus a few sensors
+ * near row 2000000 for part 1.
+ */
+fn day15(rng: &mut Rng, size: Size) -> String {
+    let mut lines = Vec::new();
+    let mut sensor = |rng: &mut Rng, (x, y): (i64, i64), radius: i64| {
+        let dx = rng.range(-radius, radius);
+        let dy = (radius - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        lines.push(format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}",
+            x,
+            y,
+            x + dx,
+            y + dy
+        ));
+    };
+
+    for (low, high) in [(0, 20), (70, 90)] {
+        for _ in 0..rng.range(1, size.count(4)) {
+            let position = (rng.range(low, high), rng.range(0, 10));
+            let radius = rng.range(position.1, 20);
+            sensor(rng, position, radius);
+        }
+    }
+    for _ in 0..rng.range(0, size.count(4)) {
+        let position = (rng.range(0, 100), rng.range(1999980, 2000020));
+        let radius = rng.range(0, 30);
+        sensor(rng, position, radius);
+    }
+    lines.join("\n") + "\n"
+}
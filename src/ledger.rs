@@ -0,0 +1,245 @@
+//! A local record of every answer ever produced, keyed by a hash of the input.
+//!
+//! The ledger is a directory holding `ledger.tsv`, with one line per
+//! (year, day, part, input SHA-256, answer, timestamp), and a copy of every
+//! input under `inputs/<sha256>.txt` so the whole ledger can be replayed.
+//! An answer is only appended when it is new for its input, so a key with
+//! several lines has changed its answer at some point.
+
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use sha2::{Digest, Sha256};
+
+use crate::registry;
+use crate::runner;
+
+/**
+ * Directory used when neither `--ledger` nor `AOC_LEDGER` says otherwise.
+ */
+pub const DEFAULT_DIR: &str = ".aoc-ledger";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub input_hash: String,
+    pub answer: String,
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+/**
+ * How a new answer compares to what the ledger already knows.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    /// This input was never solved for this part before.
+    New,
+    Unchanged,
+    Changed { previous: String },
+}
+
+/**
+ * A ledger entry whose solver now gives a different answer (or fails).
+ */
+#[derive(Debug)]
+pub struct Mismatch {
+    pub entry: Entry,
+    pub actual: Result<String, String>,
+}
+
+pub fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/**
+ * Answers may span several lines, so tabs, newlines and backslashes are escaped.
+ */
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn unescape(s: &str) -> String {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some(other) => unescaped.push(other),
+            None => unescaped.push('\\'),
+        }
+    }
+    unescaped
+}
+
+impl Entry {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\n",
+            self.year,
+            self.day,
+            self.part,
+            self.input_hash,
+            escape(&self.answer),
+            self.timestamp
+        )
+    }
+
+    fn from_line(line: &str) -> Option<Entry> {
+        let fields: Vec<&str> = line.split('\t').collect();
+        match fields[..] {
+            [year, day, part, input_hash, answer, timestamp] => Some(Entry {
+                year: year.parse().ok()?,
+                day: day.parse().ok()?,
+                part: part.parse().ok()?,
+                input_hash: input_hash.to_string(),
+                answer: unescape(answer),
+                timestamp: timestamp.parse().ok()?,
+            }),
+            _ => None,
+        }
+    }
+
+    fn same_key(&self, year: u16, day: u8, part: u8, input_hash: &str) -> bool {
+        self.year == year && self.day == day && self.part == part && self.input_hash == input_hash
+    }
+}
+
+pub struct Ledger {
+    dir: PathBuf,
+}
+
+impl Ledger {
+    pub fn open(dir: impl AsRef<Path>) -> Ledger {
+        Ledger {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /**
+     * The ledger named by the `AOC_LEDGER` environment variable, or `.aoc-ledger`.
+     */
+    pub fn from_env() -> Ledger {
+        match std::env::var_os("AOC_LEDGER") {
+            Some(dir) => Ledger::open(dir),
+            None => Ledger::open(DEFAULT_DIR),
+        }
+    }
+
+    fn file(&self) -> PathBuf {
+        self.dir.join("ledger.tsv")
+    }
+
+    fn input_path(&self, input_hash: &str) -> PathBuf {
+        self.dir.join("inputs").join(format!("{}.txt", input_hash))
+    }
+
+    /**
+     * All entries, oldest first. A missing ledger is empty and malformed
+     * lines are skipped.
+     */
+    pub fn entries(&self) -> io::Result<Vec<Entry>> {
+        match fs::read_to_string(self.file()) {
+            Ok(contents) => Ok(contents.lines().filter_map(Entry::from_line).collect()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /**
+     * Returns a stored input by its hash.
+     */
+    pub fn input(&self, input_hash: &str) -> io::Result<String> {
+        fs::read_to_string(self.input_path(input_hash))
+    }
+
+    /**
+     * Records an answer and compares it with the latest answer previously
+     * recorded for the same input.
+     */
+    pub fn record(&self, year: u16, day: u8, part: u8, input: &str, answer: &str) -> io::Result<Check> {
+        let input_hash = sha256_hex(input.as_bytes());
+        let previous = self
+            .entries()?
+            .into_iter()
+            .rev()
+            .find(|e| e.same_key(year, day, part, &input_hash))
+            .map(|e| e.answer);
+
+        let check = match previous {
+            None => Check::New,
+            Some(previous) if previous == answer => return Ok(Check::Unchanged),
+            Some(previous) => Check::Changed { previous },
+        };
+
+        fs::create_dir_all(self.dir.join("inputs"))?;
+        let input_path = self.input_path(&input_hash);
+        if !input_path.exists() {
+            fs::write(&input_path, input)?;
+        }
+
+        let entry = Entry {
+            year,
+            day,
+            part,
+            input_hash,
+            answer: answer.to_string(),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        };
+        let mut file = OpenOptions::new().create(true).append(true).open(self.file())?;
+        file.write_all(entry.to_line().as_bytes())?;
+
+        Ok(check)
+    }
+
+    /**
+     * Re-runs the solver for the latest entry of every (year, day, part, input)
+     * and returns the ones that no longer produce the recorded answer.
+     */
+    pub fn verify(&self) -> io::Result<Vec<Mismatch>> {
+        let entries = self.entries()?;
+        let mut mismatches = Vec::new();
+
+        for (i, entry) in entries.iter().enumerate() {
+            // later entries for the same key supersede this one
+            let superseded = entries[i + 1..]
+                .iter()
+                .any(|e| e.same_key(entry.year, entry.day, entry.part, &entry.input_hash));
+            if superseded {
+                continue;
+            }
+
+            let actual = match (
+                registry::find(entry.year, entry.day, entry.part),
+                self.input(&entry.input_hash),
+            ) {
+                (None, _) => Err("solution is no longer registered".to_string()),
+                (_, Err(e)) => Err(format!("stored input is unreadable: {}", e)),
+                (Some(solution), Ok(input)) => {
+                    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
+                    runner::run(solution, &lines).answer.map_err(|e| e.to_string())
+                }
+            };
+            if actual.as_ref() != Ok(&entry.answer) {
+                mismatches.push(Mismatch {
+                    entry: entry.clone(),
+                    actual,
+                });
+            }
+        }
+
+        Ok(mismatches)
+    }
+}
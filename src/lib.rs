@@ -0,0 +1,35 @@
+//! Advent of Code solutions, namespaced by year.
+//!
+//! Year-independent helpers live in [`util`], each year's days live in their
+//! own module (e.g. [`y2022`]) and [`registry`] ties them together for the CLI
+//! and the HTTP API in [`server`].
+//!
+//! The solvers can also be called directly, without going through the CLI:
+//!
+//! ```
+//! use aoc::y2022::day02;
+//!
+//! let input = vec!["A Y".to_string(), "B X".to_string(), "C Z".to_string()];
+//! let rounds = day02::parse(&input).unwrap();
+//! assert_eq!(day02::part1(&rounds), 15);
+//! assert_eq!(day02::part2(&rounds), 12);
+//! ```
+
+#![allow(clippy::needless_return, clippy::needless_range_loop)]
+
+pub mod cancel;
+pub mod convert;
+pub mod difftest;
+pub mod gen;
+pub mod ledger;
+pub mod registry;
+pub mod repl;
+pub mod report;
+pub mod runner;
+pub mod selftest;
+pub mod server;
+pub mod shrink;
+pub mod sim;
+pub mod snapshot;
+pub mod util;
+pub mod y2022;
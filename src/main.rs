@@ -0,0 +1,791 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::{BufRead, Write};
+use std::net::TcpListener;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+
+use rustyline::completion::Completer;
+use rustyline::error::ReadlineError;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::validate::Validator;
+use rustyline::{CompletionType, Config, Context, Editor, Helper};
+
+use aoc::cancel::Token;
+use aoc::convert::Format;
+use aoc::gen::Size;
+use aoc::util::rng::Rng;
+use aoc::shrink::{self, Property};
+use aoc::{difftest, gen, registry, report, runner, selftest, server, snapshot};
+use aoc::ledger::{Check, Ledger};
+use aoc::repl::{Repl, Reply};
+use aoc::sim::{Checkpoint, Session};
+use aoc::util::input::{puzzle_path, read_lines, MappedInput};
+use aoc::util::lenient;
+
+const USAGE: &str = "\
+Usage:
+    aoc run [--year YEAR] --day DAY [--part PART] [--input FILE] [--input-format text|json|csv] [--ledger DIR] [--timeout SECONDS]
+            [--variant NAME | --check] [--lenient] [--mmap]
+    aoc verify [--ledger DIR]
+    aoc list [--year YEAR]
+    aoc serve [--port PORT] [--timeout SECONDS]
+    aoc gen [--year YEAR] --day DAY [--seed N] [--size small|medium|large|FACTOR]
+    aoc difftest [--year YEAR] --day DAY [--script FILE] [--input FILE] [--cases N] [--seed N]
+    aoc report [--year YEAR] [--format html|markdown] [--output FILE] [--timeout SECONDS]
+    aoc snapshots [--year YEAR] [--day DAY] [--update-snapshots]
+    aoc convert [--year YEAR] --day DAY [--input FILE] [--from text|json|csv] [--to text|json|csv]
+    aoc shrink [--year YEAR] --day DAY --part PART [--input FILE | --seed N --size SIZE] [--panics [--variant NAME]]
+            [--timeout SECONDS]
+    aoc sim [--year YEAR] --day DAY --part PART [--input FILE] [--resume FILE] [--steps N]
+            [--checkpoint FILE [--every N]] [--interactive]
+    aoc selftest [--dir DIR] [--timeout SECONDS]
+    aoc repl [--year YEAR] --day DAY [--input FILE]";
+
+/**
+ * Flags that take no value.
+ */
+const SWITCHES: &[&str] = &["update-snapshots", "check", "panics", "interactive", "lenient", "mmap"];
+
+/**
+ * Command line flags of the form `--name value`, or just `--name` for switches.
+ */
+struct Flags {
+    values: HashMap<String, String>,
+}
+
+impl Flags {
+    fn parse(args: &[String]) -> Result<Flags, String> {
+        let mut values = HashMap::new();
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            let name = arg
+                .strip_prefix("--")
+                .ok_or_else(|| format!("unexpected argument '{}'", arg))?;
+            if SWITCHES.contains(&name) {
+                values.insert(name.to_string(), String::new());
+                continue;
+            }
+            let value = iter
+                .next()
+                .ok_or_else(|| format!("missing value for '--{}'", name))?;
+            values.insert(name.to_string(), value.clone());
+        }
+        Ok(Flags { values })
+    }
+
+    fn get<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>, String> {
+        match self.values.get(name) {
+            Some(value) => value
+                .parse()
+                .map(Some)
+                .map_err(|_| format!("invalid value '{}' for '--{}'", value, name)),
+            None => Ok(None),
+        }
+    }
+
+    fn switch(&self, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+
+    /**
+     * The answer ledger from `--ledger`, `AOC_LEDGER` or the default location.
+     */
+    fn ledger(&self) -> Result<Ledger, String> {
+        match self.get::<String>("ledger")? {
+            Some(dir) => Ok(Ledger::open(dir)),
+            None => Ok(Ledger::from_env()),
+        }
+    }
+
+    /**
+     * The per-solver time limit from `--timeout`, in (possibly fractional) seconds.
+     */
+    fn timeout(&self) -> Result<Option<Duration>, String> {
+        match self.get::<f64>("timeout")? {
+            Some(seconds) if seconds > 0.0 && seconds.is_finite() => Ok(Some(Duration::from_secs_f64(seconds))),
+            Some(seconds) => Err(format!("invalid value '{}' for '--timeout'", seconds)),
+            None => Ok(None),
+        }
+    }
+
+    fn year(&self) -> Result<u16, String> {
+        match self.get("year")? {
+            Some(year) => Ok(year),
+            None => registry::latest_year().ok_or_else(|| "no solutions registered".to_string()),
+        }
+    }
+}
+
+/**
+ * Runs one or both parts of a day and prints the answers.
+ */
+fn run(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: u8 = flags.get("day")?.ok_or("missing '--day'")?;
+    let part: Option<u8> = flags.get("part")?;
+    let variant: String = flags.get("variant")?.unwrap_or(registry::DEFAULT_VARIANT.to_string());
+    let path: PathBuf = match flags.get::<String>("input")? {
+        Some(path) => path.into(),
+        None => puzzle_path(year, day, "input.txt"),
+    };
+
+    let parts: Vec<_> = registry::for_day(year, day)
+        .filter(|s| part.is_none_or(|p| s.part == p))
+        .collect();
+    if parts.is_empty() {
+        return Err(match part {
+            Some(part) => format!("{} day {} part {} is not implemented", year, day, part),
+            None => format!("{} day {} is not implemented", year, day),
+        });
+    }
+    let mut solutions = Vec::new();
+    for default in parts {
+        match registry::find_variant(year, day, default.part, &variant) {
+            Some(solution) => solutions.push(solution),
+            // parts without the variant keep their only implementation
+            None if part.is_none() => solutions.push(default),
+            None => return Err(format!("{} day {} part {} has no variant '{}'", year, day, default.part, variant)),
+        }
+    }
+    if !path.exists() {
+        return Err(format!("input file '{}' not found", path.display()));
+    }
+
+    let mapped = if flags.switch("mmap") { Some(MappedInput::open(&path)?) } else { None };
+    let mut input = match &mapped {
+        Some(mapped) => Cow::Borrowed(mapped.text()?),
+        None => Cow::Owned(std::fs::read_to_string(&path).map_err(|e| e.to_string())?),
+    };
+    let format: Format = flags.get("input-format")?.unwrap_or(Format::Text);
+    if format != Format::Text {
+        // solve (and record in the ledger) the puzzle text the data stands for
+        let converter = registry::converter_for(year, day)
+            .ok_or_else(|| format!("{} day {} has no structured input", year, day))?;
+        input = Cow::Owned((converter.convert)(&input, format, Format::Text)?);
+    }
+    // a mapped input is solved in place by the parts that can borrow its lines
+    let zero_copy = |solution: &registry::Solution| match (&mapped, solution.variant) {
+        (Some(_), registry::DEFAULT_VARIANT) => registry::zero_copy_for(year, day, solution.part),
+        _ => None,
+    };
+    let lines: Vec<String> = if flags.switch("check") || solutions.iter().any(|s| zero_copy(s).is_none()) {
+        input.lines().map(|s| s.to_string()).collect()
+    } else {
+        Vec::new()
+    };
+    let timeout = flags.timeout()?;
+    if flags.switch("check") {
+        return check_variants(&solutions, &lines, timeout);
+    }
+    let ledger = flags.ledger()?;
+    let lenient = flags.switch("lenient");
+    for (index, solution) in solutions.into_iter().enumerate() {
+        // every part gets the full time limit
+        let token = match timeout {
+            Some(timeout) => Token::with_timeout(timeout),
+            None => Token::new(),
+        };
+        let solve = || match zero_copy(solution) {
+            Some(zero_copy) => runner::run_zero_copy(zero_copy, &input, &token),
+            None => runner::run_with(solution, &lines, &token),
+        };
+        let (outcome, warnings) = if lenient { lenient::run(solve) } else { (solve(), Vec::new()) };
+        // both parts parse the same input, so its warnings are shown once
+        if index == 0 && !warnings.is_empty() {
+            for warning in &warnings {
+                eprintln!("warning: {}", warning);
+            }
+            eprintln!("warning: {}", lenient::summary(&warnings));
+        }
+        let answer = match outcome.answer {
+            Ok(answer) => answer,
+            Err(message) => return Err(format!("part {}: {}", solution.part, message)),
+        };
+        // pictures such as day 10's CRT read better starting on their own line
+        if answer.contains('\n') {
+            println!("Part {}:\n{}", solution.part, answer);
+        } else {
+            println!("Part {}: {}", solution.part, answer);
+        }
+
+        // the answer is to the input without its malformed lines, not to the input
+        if !warnings.is_empty() {
+            continue;
+        }
+        let check = ledger.record(year, day, solution.part, &input, &answer);
+        match check {
+            Ok(Check::Changed { previous }) => {
+                eprintln!("!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!");
+                eprintln!("WARNING: {} day {} part {} changed its answer for this input", year, day, solution.part);
+                eprintln!("    before: {}", previous);
+                eprintln!("    now:    {}", answer);
+                eprintln!("!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!");
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("warning: could not update the answer ledger: {}", e),
+        }
+    }
+    Ok(())
+}
+
+/**
+ * Runs every variant of the parts on the input and fails if any two disagree.
+ */
+fn check_variants(parts: &[&registry::Solution], lines: &[String], timeout: Option<Duration>) -> Result<(), String> {
+    let mut disagreements = 0;
+    for solution in parts {
+        let variants: Vec<_> = registry::variants(solution.year, solution.day, solution.part).collect();
+        let check = runner::cross_check(&variants, lines, timeout);
+        let verdict = if check.agrees() { "agree" } else { "DISAGREE" };
+        println!("Part {}: {} variant(s) {}", solution.part, variants.len(), verdict);
+        for (variant, outcome) in &check.outcomes {
+            let answer = match &outcome.answer {
+                Ok(answer) => answer.replace('\n', "\n    "),
+                Err(failure) => format!("error: {}", failure),
+            };
+            println!("    {} ({:.1?}): {}", variant.variant, outcome.elapsed, answer);
+        }
+        if !check.agrees() {
+            disagreements += 1;
+        }
+    }
+
+    if disagreements > 0 {
+        return Err(format!("the variants of {} part(s) disagree", disagreements));
+    }
+    Ok(())
+}
+
+/**
+ * Replays every input in the answer ledger and reports answers that changed.
+ */
+fn verify(flags: &Flags) -> Result<(), String> {
+    let ledger = flags.ledger()?;
+    let entries = ledger.entries().map_err(|e| e.to_string())?;
+    let mismatches = ledger.verify().map_err(|e| e.to_string())?;
+
+    for mismatch in &mismatches {
+        let entry = &mismatch.entry;
+        println!(
+            "{} day {} part {} on input {}:",
+            entry.year,
+            entry.day,
+            entry.part,
+            &entry.input_hash[..12]
+        );
+        println!("    recorded: {}", entry.answer);
+        match &mismatch.actual {
+            Ok(answer) => println!("    now:      {}", answer),
+            Err(message) => println!("    now:      error: {}", message),
+        }
+    }
+
+    if mismatches.is_empty() {
+        println!("{} ledger entries verified", entries.len());
+        Ok(())
+    } else {
+        Err(format!("{} answer(s) changed", mismatches.len()))
+    }
+}
+
+/**
+ * Prints every implemented year/day with its parts.
+ */
+fn list(flags: &Flags) -> Result<(), String> {
+    let year: Option<u16> = flags.get("year")?;
+    let mut days: Vec<(u16, u8, Vec<u8>)> = Vec::new();
+    for solution in registry::all().filter(|s| year.is_none_or(|y| s.year == y)) {
+        match days.last_mut() {
+            Some((y, d, parts)) if *y == solution.year && *d == solution.day => {
+                parts.push(solution.part)
+            }
+            _ => days.push((solution.year, solution.day, vec![solution.part])),
+        }
+    }
+
+    for (year, day, parts) in days {
+        let mut names = Vec::new();
+        for &part in &parts {
+            let variants: Vec<&str> = registry::variants(year, day, part)
+                .map(|s| s.variant)
+                .filter(|&v| v != registry::DEFAULT_VARIANT)
+                .collect();
+            if variants.is_empty() {
+                names.push(part.to_string());
+            } else {
+                names.push(format!("{} (also {})", part, variants.join(", ")));
+            }
+        }
+        println!("{} day {:02}: part {}", year, day, names.join(", "));
+    }
+    Ok(())
+}
+
+/**
+ * Serves the solvers over HTTP on localhost until killed.
+ */
+fn serve(flags: &Flags) -> Result<(), String> {
+    let port: u16 = flags.get("port")?.unwrap_or(8080);
+    let listener = TcpListener::bind(("127.0.0.1", port)).map_err(|e| e.to_string())?;
+    eprintln!("listening on http://127.0.0.1:{}", port);
+    let timeout = flags.timeout()?.unwrap_or(server::DEFAULT_TIMEOUT);
+    server::serve(listener, timeout, |e| eprintln!("connection error: {}", e)).map_err(|e| e.to_string())
+}
+
+/**
+ * Prints a random input for a day.
+ */
+fn generate(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: u8 = flags.get("day")?.ok_or("missing '--day'")?;
+    let seed: u64 = flags.get("seed")?.unwrap_or(0);
+    let size: Size = flags.get("size")?.unwrap_or(Size::SMALL);
+
+    let input = gen::generate(year, day, &mut Rng::new(seed), size)
+        .ok_or_else(|| format!("no input generator for {} day {}", year, day))?;
+    print!("{}", input);
+    Ok(())
+}
+
+/**
+ * Compares a day's Rust solution with its Python script on the example input,
+ * an optional extra input and a number of randomly generated ones.
+ */
+fn diff_test(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: u8 = flags.get("day")?.ok_or("missing '--day'")?;
+    let script: PathBuf = match flags.get::<String>("script")? {
+        Some(path) => path.into(),
+        None => puzzle_path(year, day, &format!("day{}.py", day)),
+    };
+    let cases: u64 = flags.get("cases")?.unwrap_or(20);
+    let seed: u64 = flags.get("seed")?.unwrap_or(0);
+
+    let mut inputs: Vec<(String, String)> = Vec::new();
+    let mut files = vec![puzzle_path(year, day, "test.txt")];
+    if let Some(path) = flags.get::<String>("input")? {
+        files.push(path.into());
+    }
+    for path in files.iter().filter(|path| path.exists()) {
+        let input = std::fs::read_to_string(path).map_err(|e| e.to_string())?;
+        inputs.push((path.display().to_string(), input));
+    }
+    for case in 0..cases {
+        let mut rng = Rng::new(seed + case);
+        match gen::generate(year, day, &mut rng, Size::SMALL) {
+            Some(input) => inputs.push((format!("random input (seed {})", seed + case), input)),
+            None => return Err(format!("no input generator for {} day {}", year, day)),
+        }
+    }
+
+    let mut failures = 0;
+    for (name, input) in &inputs {
+        let disagreements = difftest::compare(year, day, &script, input);
+        if disagreements.is_empty() {
+            println!("{}: ok", name);
+        }
+        for d in disagreements {
+            failures += 1;
+            println!("{}: part {} disagrees", name, d.part);
+            println!("    rust:   {:?}", d.rust);
+            println!("    python: {:?}", d.python);
+            println!("    input:\n{}", d.input);
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} disagreement(s) in {} input(s)", failures, inputs.len()));
+    }
+    Ok(())
+}
+
+/**
+ * Writes the report of a whole year, to a file or to stdout.
+ */
+fn write_report(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let format = flags.get::<String>("format")?.unwrap_or("html".to_string());
+    let options = report::Options {
+        root: Path::new("."),
+        timeout: flags.timeout()?,
+    };
+
+    let reports = report::calendar(year, &options);
+    let page = match format.as_str() {
+        "html" => report::to_html(year, &reports),
+        "markdown" | "md" => report::to_markdown(year, &reports),
+        _ => return Err(format!("unknown report format '{}'", format)),
+    };
+
+    match flags.get::<String>("output")? {
+        Some(path) => std::fs::write(&path, page).map_err(|e| format!("cannot write '{}': {}", path, e)),
+        None => {
+            print!("{}", page);
+            Ok(())
+        }
+    }
+}
+
+/**
+ * Checks (or updates) the stored renderings of the example inputs.
+ */
+fn snapshots(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: Option<u8> = flags.get("day")?;
+    let update = flags.switch("update-snapshots") || snapshot::update_from_env();
+
+    let mut days: Vec<u8> = registry::all()
+        .filter(|s| s.year == year && day.is_none_or(|d| s.day == d))
+        .map(|s| s.day)
+        .collect();
+    days.dedup();
+
+    let mut failures = 0;
+    for day in days {
+        let example = puzzle_path(year, day, "test.txt");
+        let Ok(input) = std::fs::read_to_string(&example) else {
+            continue;
+        };
+        for rendering in snapshot::renderings(year, day, &input) {
+            let path = snapshot::path(Path::new(""), year, day, &rendering.name);
+            match snapshot::check(&path, &rendering.text, update).map_err(|e| e.to_string())? {
+                snapshot::Check::Matches => println!("{}: ok", path.display()),
+                snapshot::Check::Updated => println!("{}: updated", path.display()),
+                snapshot::Check::Missing => {
+                    failures += 1;
+                    println!("{}: missing", path.display());
+                }
+                snapshot::Check::Changed { diff } => {
+                    failures += 1;
+                    println!("{}: changed\n{}", path.display(), diff);
+                }
+            }
+        }
+    }
+
+    if failures > 0 {
+        return Err(format!("{} snapshot(s) out of date, rerun with --update-snapshots if intended", failures));
+    }
+    Ok(())
+}
+
+/**
+ * Converts a day's input between the puzzle text, JSON and CSV and prints it.
+ */
+fn convert(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: u8 = flags.get("day")?.ok_or("missing '--day'")?;
+    let path: PathBuf = match flags.get::<String>("input")? {
+        Some(path) => path.into(),
+        None => puzzle_path(year, day, "input.txt"),
+    };
+    let from: Format = flags.get("from")?.unwrap_or(Format::Text);
+    let to: Format = flags.get("to")?.unwrap_or(Format::Json);
+
+    let converter = registry::converter_for(year, day)
+        .ok_or_else(|| format!("{} day {} has no structured input", year, day))?;
+    let input = std::fs::read_to_string(&path).map_err(|e| format!("cannot read '{}': {}", path.display(), e))?;
+    print!("{}", (converter.convert)(&input, from, to)?);
+    Ok(())
+}
+
+/**
+ * Shrinks an input on which the variants of a part disagree, or on which a
+ * solver panics, and saves the result among the day's test cases.
+ */
+fn shrink_input(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: u8 = flags.get("day")?.ok_or("missing '--day'")?;
+    let part: u8 = flags.get("part")?.ok_or("missing '--part'")?;
+    let timeout = flags.timeout()?.unwrap_or(shrink::DEFAULT_TIMEOUT);
+
+    let input = match flags.get::<String>("input")? {
+        Some(path) => std::fs::read_to_string(&path).map_err(|e| format!("cannot read '{}': {}", path, e))?,
+        None => {
+            let seed: u64 = flags.get("seed")?.unwrap_or(0);
+            let size: Size = flags.get("size")?.unwrap_or(Size::SMALL);
+            gen::generate(year, day, &mut Rng::new(seed), size)
+                .ok_or_else(|| format!("no input generator for {} day {}", year, day))?
+        }
+    };
+    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
+
+    let property = if flags.switch("panics") {
+        let variant: String = flags.get("variant")?.unwrap_or(registry::DEFAULT_VARIANT.to_string());
+        let solution = registry::find_variant(year, day, part, &variant)
+            .ok_or_else(|| format!("{} day {} part {} has no variant '{}'", year, day, part, variant))?;
+        Property::Panic(solution)
+    } else {
+        if registry::variants(year, day, part).count() < 2 {
+            return Err(format!("{} day {} part {} has a single variant", year, day, part));
+        }
+        Property::Disagreement { year, day, part }
+    };
+
+    // the panics of every attempt would drown the progress
+    std::panic::set_hook(Box::new(|_| {}));
+    if !property.fails(&lines, timeout) {
+        return Err("the input does not fail".to_string());
+    }
+    let mut attempts = 0;
+    let minimal = shrink::minimize(&lines, |candidate| {
+        attempts += 1;
+        property.fails(candidate, timeout)
+    });
+    let _ = std::panic::take_hook();
+
+    let path = shrink::save_case(Path::new("."), year, day, &minimal).map_err(|e| e.to_string())?;
+    println!(
+        "shrunk {} lines to {} in {} attempts, saved to {}",
+        lines.len(),
+        minimal.len(),
+        attempts,
+        path.display()
+    );
+    for line in &minimal {
+        println!("    {}", line);
+    }
+    Ok(())
+}
+
+/**
+ * How many steps `aoc sim` takes between two checkpoints by default.
+ */
+const CHECKPOINT_EVERY: u64 = 1000;
+
+/**
+ * Runs a part's simulation, either for a number of steps or to the end while
+ * saving checkpoints, or one command at a time with `--interactive`.
+ */
+fn simulate(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: u8 = flags.get("day")?.ok_or("missing '--day'")?;
+    let part: u8 = flags.get("part")?.ok_or("missing '--part'")?;
+    let path: PathBuf = match flags.get::<String>("input")? {
+        Some(path) => path.into(),
+        None => puzzle_path(year, day, "input.txt"),
+    };
+
+    let simulator = registry::simulator_for(year, day, part)
+        .ok_or_else(|| format!("{} day {} part {} is not a simulation", year, day, part))?;
+    let lines = read_lines(&path).map_err(|e| format!("cannot read '{}': {}", path.display(), e))?;
+    let mut session = (simulator.start)(&lines)?;
+    if let Some(resume) = flags.get::<String>("resume")? {
+        session.resume(Checkpoint::load(Path::new(&resume))?)?;
+    }
+
+    if flags.switch("interactive") {
+        return step_through(&mut session);
+    }
+
+    let limit: Option<u64> = flags.get("steps")?;
+    let checkpoint: Option<PathBuf> = flags.get::<String>("checkpoint")?.map(PathBuf::from);
+    let every: u64 = flags.get("every")?.unwrap_or(CHECKPOINT_EVERY);
+    if every == 0 {
+        return Err("invalid value '0' for '--every'".to_string());
+    }
+    let mut remaining = limit.unwrap_or(u64::MAX);
+    loop {
+        let batch = if checkpoint.is_some() { every.min(remaining) } else { remaining };
+        remaining -= session.run(Some(batch))?;
+        if let Some(path) = &checkpoint {
+            session.checkpoint()?.save(path)?;
+        }
+        if session.is_finished() || remaining == 0 {
+            break;
+        }
+    }
+
+    println!("{}", session.render());
+    let status = if session.is_finished() { "finished" } else { "paused" };
+    println!("{} after {} steps", status, session.steps());
+    Ok(())
+}
+
+/**
+ * Reads commands from stdin to step through a simulation, printing the state
+ * after each.
+ */
+fn step_through(session: &mut Session) -> Result<(), String> {
+    const HELP: &str = "commands: s [N] to step, b [N] to go back, r to run to the end, w FILE to save a checkpoint, q to quit";
+    println!("{}\n{}", HELP, session.render());
+    let stdin = std::io::stdin();
+    loop {
+        print!("step {}> ", session.steps());
+        std::io::stdout().flush().map_err(|e| e.to_string())?;
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).map_err(|e| e.to_string())? == 0 {
+            println!();
+            return Ok(());
+        }
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let count = || -> Result<u64, String> {
+            match words.get(1) {
+                Some(n) => n.parse().map_err(|_| format!("'{}' is not a number of steps", n)),
+                None => Ok(1),
+            }
+        };
+        let result = match words[..] {
+            [] | ["s", ..] => count().and_then(|n| session.run(Some(n))).map(|_| ()),
+            ["b", ..] => count().and_then(|n| session.back(n)).map(|_| ()),
+            ["r"] => session.run(None).map(|_| ()),
+            ["w", file] => session.checkpoint().and_then(|c| c.save(Path::new(file))),
+            ["q"] => return Ok(()),
+            _ => Err(HELP.to_string()),
+        };
+        match result {
+            Ok(()) => {
+                println!("{}", session.render());
+                if session.is_finished() {
+                    println!("finished after {} steps", session.steps());
+                }
+            }
+            Err(message) => println!("{}", message),
+        }
+    }
+}
+
+/**
+ * Completes the command name at the start of a REPL line.
+ */
+struct CommandCompleter<'a>(&'a Repl);
+
+impl Completer for CommandCompleter<'_> {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _: &Context) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = line.len() - line.trim_start().len();
+        let word = &line[start.min(pos)..pos];
+        // the arguments are numbers and paths, only the command is completed
+        if word.contains(char::is_whitespace) {
+            return Ok((pos, Vec::new()));
+        }
+        let names = self.0.complete(word).into_iter().map(|name| name.to_string()).collect();
+        Ok((start, names))
+    }
+}
+
+impl Hinter for CommandCompleter<'_> {
+    type Hint = String;
+}
+
+impl Highlighter for CommandCompleter<'_> {}
+
+impl Validator for CommandCompleter<'_> {}
+
+impl Helper for CommandCompleter<'_> {}
+
+/**
+ * Parses an input and answers queries about it typed at a prompt.
+ */
+fn query(flags: &Flags) -> Result<(), String> {
+    let year = flags.year()?;
+    let day: u8 = flags.get("day")?.ok_or("missing '--day'")?;
+    let path: PathBuf = match flags.get::<String>("input")? {
+        Some(path) => path.into(),
+        None => puzzle_path(year, day, "input.txt"),
+    };
+
+    let querier = registry::querier_for(year, day).ok_or_else(|| format!("{} day {} has no queries", year, day))?;
+    let lines = read_lines(&path).map_err(|e| format!("cannot read '{}': {}", path.display(), e))?;
+    let repl = (querier.start)(&lines)?;
+
+    let config = Config::builder().completion_type(CompletionType::List).build();
+    let mut editor = Editor::with_config(config).map_err(|e| e.to_string())?;
+    editor.set_helper(Some(CommandCompleter(&repl)));
+    println!("{} day {}, 'help' lists the queries and tab completes them", year, day);
+    loop {
+        let line = match editor.readline(&format!("day {}> ", day)) {
+            Ok(line) => line,
+            Err(ReadlineError::Eof | ReadlineError::Interrupted) => return Ok(()),
+            Err(e) => return Err(e.to_string()),
+        };
+        let _ = editor.add_history_entry(line.as_str());
+        match repl.eval(&line) {
+            Ok(Reply::Output(output)) if output.is_empty() => {}
+            Ok(Reply::Output(output)) => println!("{}", output),
+            Ok(Reply::Quit) => return Ok(()),
+            Err(message) => println!("error: {}", message),
+        }
+    }
+}
+
+/**
+ * Checks every solver on the example inputs, compiled into the binary or read
+ * from `--dir`, the current directory by default.
+ */
+fn self_test(flags: &Flags) -> Result<(), String> {
+    let examples = match (flags.get::<String>("dir")?, selftest::embedded()) {
+        (None, Some(embedded)) => embedded,
+        (dir, _) => {
+            let dir = dir.unwrap_or(".".to_string());
+            let examples = selftest::load(Path::new(&dir));
+            if examples.is_empty() {
+                return Err(format!(
+                    "no examples under '{}', run from the source tree or build with '--features embed-examples'",
+                    dir
+                ));
+            }
+            examples
+        }
+    };
+
+    let checks = selftest::run(&examples, flags.timeout()?);
+    let mut failures = 0;
+    for check in &checks {
+        let solution = check.solution;
+        let name = match solution.variant {
+            registry::DEFAULT_VARIANT => format!("{} day {} part {}", solution.year, solution.day, solution.part),
+            variant => format!("{} day {} part {} ({})", solution.year, solution.day, solution.part, variant),
+        };
+        if check.passed() {
+            println!("ok      {} in {:.1?}", name, check.elapsed);
+            continue;
+        }
+        failures += 1;
+        match &check.actual {
+            Ok(actual) => println!("FAILED  {}\n    expected: {}\n    got:      {}", name, check.expected, actual),
+            Err(message) => println!("FAILED  {}\n    expected: {}\n    error:    {}", name, check.expected, message),
+        }
+    }
+
+    println!("{} passed, {} failed", checks.len() - failures, failures);
+    if failures > 0 {
+        return Err(format!("{} of {} checks failed", failures, checks.len()));
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let result = match args.split_first() {
+        Some((command, rest)) => Flags::parse(rest).and_then(|flags| match command.as_str() {
+            "run" => run(&flags),
+            "list" => list(&flags),
+            "verify" => verify(&flags),
+            "serve" => serve(&flags),
+            "gen" => generate(&flags),
+            "difftest" => diff_test(&flags),
+            "report" => write_report(&flags),
+            "snapshots" => snapshots(&flags),
+            "convert" => convert(&flags),
+            "shrink" => shrink_input(&flags),
+            "sim" => simulate(&flags),
+            "selftest" => self_test(&flags),
+            "repl" => query(&flags),
+            _ => Err(format!("unknown command '{}'", command)),
+        }),
+        None => Err("missing command".to_string()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
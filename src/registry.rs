@@ -0,0 +1,339 @@
+//! The table of every implemented (year, day, part) solution.
+
+use std::fmt::Display;
+
+use crate::convert::Format;
+use crate::repl::Repl;
+use crate::sim::Session;
+use crate::y2022;
+
+/**
+ * Solves one part of one day given the lines of the puzzle input,
+ * returning the answer formatted for display or why there is none.
+ */
+pub type SolveFn = fn(&[String]) -> Result<String, String>;
+
+/**
+ * What a `partN` function may return: a plain answer, `None` when the input
+ * has no answer, or a `Result` of either.
+ */
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<String, String>;
+}
+
+macro_rules! plain_answers {
+    ($($t:ty),*) => {$(
+        impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        }
+    )*};
+}
+
+plain_answers!(i32, i64, u32, u64, usize, String);
+
+impl<T: IntoAnswer> IntoAnswer for Option<T> {
+    fn into_answer(self) -> Result<String, String> {
+        match self {
+            Some(answer) => answer.into_answer(),
+            None => Err(NO_ANSWER.to_string()),
+        }
+    }
+}
+
+impl<T: IntoAnswer, E: Display> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<String, String> {
+        self.map_err(|e| e.to_string())?.into_answer()
+    }
+}
+
+/**
+ * The error of a part that returned `None`.
+ */
+pub const NO_ANSWER: &str = "the input has no answer";
+
+/**
+ * The name of the variant every part has, used unless another one is asked for.
+ */
+pub const DEFAULT_VARIANT: &str = "default";
+
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Which of the part's implementations this is, e.g. `fast`.
+    pub variant: &'static str,
+    pub solve: SolveFn,
+}
+
+/**
+ * Registers a day module's `partN` function, optionally as a named variant of
+ * the part. The input is parsed with the module's `parse` and the answer may be
+ * anything implementing [`IntoAnswer`].
+ */
+macro_rules! solution {
+    ($year:expr, $day:expr, $part:expr, $module:ident :: $solve:ident) => {
+        $crate::registry::solution!($year, $day, $part, $crate::registry::DEFAULT_VARIANT, $module::$solve)
+    };
+    ($year:expr, $day:expr, $part:expr, $variant:expr, $module:ident :: $solve:ident) => {
+        $crate::registry::Solution {
+            year: $year,
+            day: $day,
+            part: $part,
+            variant: $variant,
+            solve: |input| {
+                let model = $module::parse(input)?;
+                $crate::registry::IntoAnswer::into_answer($module::$solve(&model))
+            },
+        }
+    };
+}
+pub(crate) use solution;
+
+/**
+ * Converts one day's input from one form to another by way of its parsed
+ * model, e.g. from the puzzle text to JSON.
+ */
+pub type ConvertFn = fn(&str, Format, Format) -> Result<String, String>;
+
+#[derive(Clone, Copy)]
+pub struct Converter {
+    pub year: u16,
+    pub day: u8,
+    pub convert: ConvertFn,
+}
+
+/**
+ * Registers a day module's model for conversion. The puzzle text is read with
+ * the module's `parse` and written with its `to_text`, the structured forms
+ * through the model's serde implementation.
+ */
+macro_rules! converter {
+    ($year:expr, $day:expr, $module:ident) => {
+        $crate::registry::Converter {
+            year: $year,
+            day: $day,
+            convert: |data, from, to| {
+                let model = match from {
+                    $crate::convert::Format::Text => {
+                        let lines: Vec<String> = data.lines().map(|s| s.to_string()).collect();
+                        $module::parse(&lines)?
+                    }
+                    format => $crate::convert::decode(data, format)?,
+                };
+                match to {
+                    $crate::convert::Format::Text => Ok($module::to_text(&model)),
+                    format => $crate::convert::encode(&model, format),
+                }
+            },
+        }
+    };
+}
+pub(crate) use converter;
+
+/**
+ * Starts the simulation of one part on an input.
+ */
+pub type SimulateFn = fn(&[String]) -> Result<Session, String>;
+
+#[derive(Clone, Copy)]
+pub struct Simulator {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub start: SimulateFn,
+}
+
+/**
+ * Registers a day module's function building the [`Simulation`] of a part
+ * from its parsed model, so that the part can be stepped through.
+ *
+ * [`Simulation`]: crate::sim::Simulation
+ */
+macro_rules! simulator {
+    ($year:expr, $day:expr, $part:expr, $module:ident :: $start:ident) => {
+        $crate::registry::Simulator {
+            year: $year,
+            day: $day,
+            part: $part,
+            start: |input| {
+                let model = $module::parse(input)?;
+                let origin = $crate::sim::Origin::of($year, $day, $part, input);
+                $crate::sim::Session::new($module::$start(&model), origin)
+            },
+        }
+    };
+}
+pub(crate) use simulator;
+
+/**
+ * Parses an input into a [`Repl`] answering questions about it.
+ */
+pub type QueryFn = fn(&[String]) -> Result<Repl, String>;
+
+#[derive(Clone, Copy)]
+pub struct Querier {
+    pub year: u16,
+    pub day: u8,
+    pub start: QueryFn,
+}
+
+/**
+ * Registers a day module's `QUERIES` on the model read by its `parse`.
+ */
+macro_rules! querier {
+    ($year:expr, $day:expr, $module:ident) => {
+        $crate::registry::Querier {
+            year: $year,
+            day: $day,
+            start: |input| Ok($crate::repl::Repl::new($module::parse(input)?, $module::QUERIES)),
+        }
+    };
+}
+pub(crate) use querier;
+
+/**
+ * Solves a part straight from the input text, without copying its lines.
+ */
+pub type SolveTextFn = fn(&str) -> Result<String, String>;
+
+#[derive(Clone, Copy)]
+pub struct ZeroCopy {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub solve: SolveTextFn,
+}
+
+/**
+ * Registers a day module's `partN` function on the model read by its
+ * `parse_lines`, which borrows the lines of the input text.
+ */
+macro_rules! zero_copy {
+    ($year:expr, $day:expr, $part:expr, $module:ident :: $solve:ident) => {
+        $crate::registry::ZeroCopy {
+            year: $year,
+            day: $day,
+            part: $part,
+            solve: |text| {
+                let model = $module::parse_lines(text.lines())?;
+                $crate::registry::IntoAnswer::into_answer($module::$solve(&model))
+            },
+        }
+    };
+}
+pub(crate) use zero_copy;
+
+/**
+ * Every year's solutions, oldest year first.
+ */
+const YEARS: &[&[Solution]] = &[y2022::SOLUTIONS];
+
+/**
+ * Every year's convertible models, oldest year first.
+ */
+const MODELS: &[&[Converter]] = &[y2022::CONVERTERS];
+
+/**
+ * Every year's step-by-step simulations, oldest year first.
+ */
+const SIMULATIONS: &[&[Simulator]] = &[y2022::SIMULATORS];
+
+/**
+ * Every year's days that can be queried, oldest year first.
+ */
+const QUERIERS: &[&[Querier]] = &[y2022::QUERIERS];
+
+/**
+ * Every year's parts that can be solved without copying the input, oldest
+ * year first.
+ */
+const ZERO_COPY: &[&[ZeroCopy]] = &[y2022::ZERO_COPY];
+
+/**
+ * Returns every variant of every implemented solution ordered by year, day and
+ * part, the default variant of a part first.
+ */
+pub fn all_variants() -> impl Iterator<Item = &'static Solution> {
+    YEARS.iter().flat_map(|year| year.iter())
+}
+
+/**
+ * Returns all implemented solutions ordered by year, day and part, one per
+ * part in its default variant.
+ */
+pub fn all() -> impl Iterator<Item = &'static Solution> {
+    all_variants().filter(|s| s.variant == DEFAULT_VARIANT)
+}
+
+/**
+ * Returns every variant of one part.
+ */
+pub fn variants(year: u16, day: u8, part: u8) -> impl Iterator<Item = &'static Solution> {
+    all_variants().filter(move |s| s.year == year && s.day == day && s.part == part)
+}
+
+/**
+ * Looks up a single variant of a solution.
+ */
+pub fn find_variant(year: u16, day: u8, part: u8, variant: &str) -> Option<&'static Solution> {
+    variants(year, day, part).find(|s| s.variant == variant)
+}
+
+/**
+ * Returns the solutions registered for one day of one year.
+ */
+pub fn for_day(year: u16, day: u8) -> impl Iterator<Item = &'static Solution> {
+    all().filter(move |s| s.year == year && s.day == day)
+}
+
+/**
+ * Looks up a single solution.
+ */
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static Solution> {
+    for_day(year, day).find(|s| s.part == part)
+}
+
+/**
+ * Returns the most recent year with at least one solution.
+ */
+pub fn latest_year() -> Option<u16> {
+    all().map(|s| s.year).max()
+}
+
+/**
+ * Looks up the converter of a day's input.
+ */
+pub fn converter_for(year: u16, day: u8) -> Option<&'static Converter> {
+    MODELS.iter().flat_map(|year| year.iter()).find(|c| c.year == year && c.day == day)
+}
+
+/**
+ * Looks up the simulation of a part.
+ */
+pub fn simulator_for(year: u16, day: u8, part: u8) -> Option<&'static Simulator> {
+    SIMULATIONS
+        .iter()
+        .flat_map(|year| year.iter())
+        .find(|s| s.year == year && s.day == day && s.part == part)
+}
+
+/**
+ * Looks up the queries of a day.
+ */
+pub fn querier_for(year: u16, day: u8) -> Option<&'static Querier> {
+    QUERIERS.iter().flat_map(|year| year.iter()).find(|q| q.year == year && q.day == day)
+}
+
+/**
+ * Looks up the zero-copy form of a part, which gives the same answers as its
+ * default variant.
+ */
+pub fn zero_copy_for(year: u16, day: u8, part: u8) -> Option<&'static ZeroCopy> {
+    ZERO_COPY
+        .iter()
+        .flat_map(|year| year.iter())
+        .find(|z| z.year == year && z.day == day && z.part == part)
+}
@@ -0,0 +1,170 @@
+//! Questions about a parsed puzzle input, asked one line at a time.
+//!
+//! A day offers its questions as a list of [`Query`]s on its model, such as
+//! the directories larger than some size on day 7. A [`Repl`] holds the parsed
+//! input and answers command lines naming one of them, which is what
+//! `aoc repl` reads from the terminal:
+//!
+//! ```
+//! use aoc::registry;
+//! use aoc::repl::Reply;
+//!
+//! let input = vec!["SbcdE".to_string()];
+//! let repl = (registry::querier_for(2022, 12).unwrap().start)(&input).unwrap();
+//! assert_eq!(repl.complete("dis"), ["distance"]);
+//! assert_eq!(repl.eval("distance 0 3"), Ok(Reply::Output("3 steps from the start".to_string())));
+//! ```
+
+use std::rc::Rc;
+use std::str::FromStr;
+
+/**
+ * A question about a day's model.
+ */
+pub struct Query<M> {
+    pub name: &'static str,
+    /// The arguments as shown in the help, optional ones in brackets.
+    pub args: &'static str,
+    pub help: &'static str,
+    pub run: fn(&M, &[&str]) -> Result<String, String>,
+}
+
+impl<M> Query<M> {
+    /**
+     * The most arguments the query takes, one per word of its `args`.
+     */
+    fn max_args(&self) -> usize {
+        self.args.split_whitespace().count()
+    }
+}
+
+/**
+ * What a command line gave.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Reply {
+    Output(String),
+    Quit,
+}
+
+/**
+ * Answers a query about the model it was bound to.
+ */
+type Answer = Box<dyn Fn(&[&str]) -> Result<String, String>>;
+
+/**
+ * A query bound to the model it asks about.
+ */
+struct Command {
+    name: &'static str,
+    args: &'static str,
+    help: &'static str,
+    max_args: usize,
+    run: Answer,
+}
+
+/**
+ * The commands understood besides a day's queries.
+ */
+const BUILTINS: &[&str] = &["help", "quit"];
+
+/**
+ * A parsed input and the queries that can be asked about it.
+ */
+pub struct Repl {
+    commands: Vec<Command>,
+}
+
+impl Repl {
+    pub fn new<M: 'static>(model: M, queries: &'static [Query<M>]) -> Repl {
+        let model = Rc::new(model);
+        let commands = queries
+            .iter()
+            .map(|query| {
+                let model = Rc::clone(&model);
+                Command {
+                    name: query.name,
+                    args: query.args,
+                    help: query.help,
+                    max_args: query.max_args(),
+                    run: Box::new(move |args| (query.run)(&model, args)),
+                }
+            })
+            .collect();
+        Repl { commands }
+    }
+
+    /**
+     * The names of every command, the day's queries first.
+     */
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.commands.iter().map(|command| command.name).chain(BUILTINS.iter().copied())
+    }
+
+    /**
+     * The command names starting with `prefix`, for tab completion.
+     */
+    pub fn complete(&self, prefix: &str) -> Vec<&'static str> {
+        self.names().filter(|name| name.starts_with(prefix)).collect()
+    }
+
+    /**
+     * Lists the commands with their arguments.
+     */
+    pub fn help(&self) -> String {
+        let usage: Vec<String> = self
+            .commands
+            .iter()
+            .map(|command| format!("{} {}", command.name, command.args).trim_end().to_string())
+            .collect();
+        let width = usage.iter().map(|usage| usage.len()).max().unwrap_or(0);
+        let mut lines: Vec<String> = usage
+            .iter()
+            .zip(&self.commands)
+            .map(|(usage, command)| format!("{:width$}  {}", usage, command.help, width = width))
+            .collect();
+        lines.push("help, quit".to_string());
+        lines.join("\n")
+    }
+
+    /**
+     * Answers one command line, an empty line with no output.
+     */
+    pub fn eval(&self, line: &str) -> Result<Reply, String> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let Some((&name, args)) = words.split_first() else {
+            return Ok(Reply::Output(String::new()));
+        };
+        match name {
+            "help" => return Ok(Reply::Output(self.help())),
+            "quit" | "exit" => return Ok(Reply::Quit),
+            _ => {}
+        }
+        let command = self
+            .commands
+            .iter()
+            .find(|command| command.name == name)
+            .ok_or_else(|| format!("unknown command '{}', try 'help'", name))?;
+        if args.len() > command.max_args {
+            return Err(format!("usage: {} {}", command.name, command.args));
+        }
+        (command.run)(args).map(Reply::Output)
+    }
+}
+
+/**
+ * Reads the argument at `index`, named `name` in error messages.
+ */
+pub fn arg<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<T, String> {
+    optional_arg(args, index, name)?.ok_or_else(|| format!("missing {}", name))
+}
+
+/**
+ * Like [`arg`], for an argument that may be left out.
+ */
+pub fn optional_arg<T: FromStr>(args: &[&str], index: usize, name: &str) -> Result<Option<T>, String> {
+    match args.get(index) {
+        Some(arg) => arg.parse().map(Some).map_err(|_| format!("'{}' is not a valid {}", arg, name)),
+        None => Ok(None),
+    }
+}
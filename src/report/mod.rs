@@ -0,0 +1,312 @@
+//! A shareable summary of a whole calendar: the answers and timings of every
+//! day together with some statistics about its input and pictures of what the
+//! solvers computed, as a self-contained HTML page or as Markdown.
+
+use std::fmt::Write;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::cancel::Token;
+use crate::registry;
+use crate::runner;
+use crate::util::input::puzzle_path;
+
+mod y2022;
+
+pub struct PartReport {
+    pub part: u8,
+    /// The answer, or why there is none.
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+/**
+ * A text rendering of something a solver computed, shown in a fixed-width font.
+ */
+#[derive(Clone)]
+pub struct Picture {
+    pub title: String,
+    pub text: String,
+}
+
+#[derive(Default)]
+pub struct DayReport {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<PartReport>,
+    /// Named facts about the input, such as its size.
+    pub stats: Vec<(String, String)>,
+    pub pictures: Vec<Picture>,
+    /// Why the day could not be reported on, e.g. a missing input.
+    pub error: Option<String>,
+}
+
+/**
+ * Where the report looks for puzzle inputs and how long each solver may take.
+ */
+pub struct Options<'a> {
+    /// The directory holding the `YEAR/dayNN/` puzzle folders.
+    pub root: &'a Path,
+    pub timeout: Option<Duration>,
+}
+
+/**
+ * A token with the timeout, if any, starting now.
+ */
+fn token(timeout: Option<Duration>) -> Token {
+    match timeout {
+        Some(timeout) => Token::with_timeout(timeout),
+        None => Token::new(),
+    }
+}
+
+/**
+ * Reports on one day, solving every registered part of it on the input.
+ *
+ * The pictures are drawn under the same timeout as each part, and a picture
+ * that cannot be drawn shows why instead.
+ */
+pub fn day(year: u16, day: u8, input: &str, timeout: Option<Duration>) -> DayReport {
+    let lines: Vec<String> = input.lines().map(|s| s.to_string()).collect();
+
+    let mut report = DayReport {
+        year,
+        day,
+        ..DayReport::default()
+    };
+    for solution in registry::for_day(year, day) {
+        let outcome = runner::run_with(solution, &lines, &token(timeout));
+        report.parts.push(PartReport {
+            part: solution.part,
+            answer: outcome.answer.map_err(|e| e.to_string()),
+            elapsed: outcome.elapsed,
+        });
+    }
+
+    report.stats.push(("Lines".to_string(), lines.len().to_string()));
+    report.stats.push(("Bytes".to_string(), input.len().to_string()));
+    let details = match year {
+        2022 => y2022::details(day, &lines, timeout),
+        _ => Ok(Details::default()),
+    };
+    match details {
+        Ok(details) => {
+            report.stats.extend(details.stats);
+            report.pictures.extend(details.pictures);
+        }
+        Err(e) => report.error = Some(format!("input does not parse: {}", e)),
+    }
+
+    report
+}
+
+/**
+ * Reports on every registered day of the year, reading each day's `input.txt`.
+ */
+pub fn calendar(year: u16, options: &Options) -> Vec<DayReport> {
+    let mut days: Vec<u8> = registry::all().filter(|s| s.year == year).map(|s| s.day).collect();
+    days.dedup();
+
+    let mut reports = Vec::new();
+    for d in days {
+        let path = options.root.join(puzzle_path(year, d, "input.txt"));
+        match std::fs::read_to_string(&path) {
+            Ok(input) => reports.push(day(year, d, &input, options.timeout)),
+            Err(e) => reports.push(DayReport {
+                year,
+                day: d,
+                error: Some(format!("cannot read {}: {}", path.display(), e)),
+                ..DayReport::default()
+            }),
+        }
+    }
+    reports
+}
+
+/**
+ * What a year's module knows about a day beyond its answers.
+ */
+#[derive(Default)]
+struct Details {
+    stats: Vec<(String, String)>,
+    pictures: Vec<Picture>,
+    /// How long drawing each picture may take.
+    timeout: Option<Duration>,
+}
+
+impl Details {
+    fn new(timeout: Option<Duration>) -> Details {
+        Details {
+            timeout,
+            ..Details::default()
+        }
+    }
+
+    fn stat(&mut self, name: &str, value: impl ToString) {
+        self.stats.push((name.to_string(), value.to_string()));
+    }
+
+    fn picture(&mut self, title: &str, text: String) {
+        self.pictures.push(Picture {
+            title: title.to_string(),
+            text,
+        });
+    }
+
+    /**
+     * Adds the picture `draw` gives, if any, drawing it like a solver runs:
+     * within the timeout and with its panics caught.
+     */
+    fn draw(&mut self, title: &str, draw: impl FnOnce() -> Option<String>) {
+        match runner::guard(&token(self.timeout), || Ok(draw())) {
+            Ok(Some(text)) => self.picture(title, text),
+            Ok(None) => {}
+            Err(failure) => self.picture(title, format!("not drawn, {}", failure)),
+        }
+    }
+}
+
+fn format_duration(elapsed: Duration) -> String {
+    let micros = elapsed.as_micros();
+    if micros < 1000 {
+        format!("{} µs", micros)
+    } else if micros < 1_000_000 {
+        format!("{:.1} ms", micros as f64 / 1000.0)
+    } else {
+        format!("{:.2} s", elapsed.as_secs_f64())
+    }
+}
+
+impl DayReport {
+    /**
+     * Every picture of the day, starting with multi-line answers such as
+     * letters drawn by pixels, which don't fit in a table.
+     */
+    pub fn renderings(&self) -> Vec<Picture> {
+        let mut pictures = Vec::new();
+        for part in &self.parts {
+            if let Ok(answer) = &part.answer {
+                if answer.contains('\n') {
+                    pictures.push(Picture {
+                        title: format!("Part {} answer", part.part),
+                        text: answer.clone(),
+                    });
+                }
+            }
+        }
+        pictures.extend(self.pictures.iter().cloned());
+        pictures
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+const STYLE: &str = "\
+body { font-family: sans-serif; max-width: 60em; margin: 2em auto; padding: 0 1em; color: #222; }
+table { border-collapse: collapse; margin: 0.5em 0; }
+th, td { border: 1px solid #ccc; padding: 0.2em 0.6em; text-align: left; vertical-align: top; }
+pre { background: #0f0f23; color: #ccc; padding: 0.5em; overflow: auto; line-height: 1; font-size: 0.8em; }
+.error { color: #b00; }
+nav a { margin-right: 0.5em; }";
+
+/**
+ * Renders the reports as a single HTML page with no external resources.
+ */
+pub fn to_html(year: u16, reports: &[DayReport]) -> String {
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>Advent of Code {}</title>\n<style>\n{}\n</style>\n</head>\n<body>\n<h1>Advent of Code {}</h1>\n<nav>",
+        year, STYLE, year
+    );
+    for report in reports {
+        let _ = write!(html, "<a href=\"#day{:02}\">Day {}</a>", report.day, report.day);
+    }
+    html.push_str("</nav>\n");
+
+    for report in reports {
+        let _ = writeln!(html, "<section id=\"day{:02}\">\n<h2>Day {}</h2>", report.day, report.day);
+        if let Some(error) = &report.error {
+            let _ = writeln!(html, "<p class=\"error\">{}</p>", escape_html(error));
+        }
+
+        if !report.parts.is_empty() {
+            html.push_str("<table>\n<tr><th>Part</th><th>Answer</th><th>Time</th></tr>\n");
+            for part in &report.parts {
+                let answer = match &part.answer {
+                    Ok(answer) if answer.contains('\n') => "see below".to_string(),
+                    Ok(answer) => format!("<code>{}</code>", escape_html(answer)),
+                    Err(e) => format!("<span class=\"error\">{}</span>", escape_html(e)),
+                };
+                let _ = writeln!(
+                    html,
+                    "<tr><td>{}</td><td>{}</td><td>{}</td></tr>",
+                    part.part,
+                    answer,
+                    format_duration(part.elapsed)
+                );
+            }
+            html.push_str("</table>\n");
+        }
+
+        if !report.stats.is_empty() {
+            html.push_str("<table>\n");
+            for (name, value) in &report.stats {
+                let _ = writeln!(html, "<tr><th>{}</th><td>{}</td></tr>", escape_html(name), escape_html(value));
+            }
+            html.push_str("</table>\n");
+        }
+
+        for picture in report.renderings() {
+            let _ = writeln!(
+                html,
+                "<h3>{}</h3>\n<pre>{}</pre>",
+                escape_html(&picture.title),
+                escape_html(&picture.text)
+            );
+        }
+        html.push_str("</section>\n");
+    }
+
+    html.push_str("</body>\n</html>\n");
+    html
+}
+
+/**
+ * Renders the reports as Markdown, with pictures in fenced code blocks.
+ */
+pub fn to_markdown(year: u16, reports: &[DayReport]) -> String {
+    let mut md = String::new();
+    let _ = writeln!(md, "# Advent of Code {}", year);
+
+    for report in reports {
+        let _ = writeln!(md, "\n## Day {}\n", report.day);
+        if let Some(error) = &report.error {
+            let _ = writeln!(md, "**Error:** {}\n", error);
+        }
+
+        if !report.parts.is_empty() {
+            md.push_str("| Part | Answer | Time |\n|---|---|---|\n");
+            for part in &report.parts {
+                let answer = match &part.answer {
+                    Ok(answer) if answer.contains('\n') => "see below".to_string(),
+                    Ok(answer) => format!("`{}`", answer),
+                    Err(e) => format!("error: {}", e.replace('|', "\\|")),
+                };
+                let _ = writeln!(md, "| {} | {} | {} |", part.part, answer, format_duration(part.elapsed));
+            }
+            md.push('\n');
+        }
+
+        for (name, value) in &report.stats {
+            let _ = writeln!(md, "- {}: {}", name, value);
+        }
+
+        for picture in report.renderings() {
+            let _ = writeln!(md, "\n### {}\n\n```text\n{}\n```", picture.title, picture.text);
+        }
+    }
+    md
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::util::point::{Direction, Point};
+use crate::y2022::*;
+
+use super::Details;
+
+/**
+ * Draws a set of points as `#` on `.`, marking the origin with `s`.
+ */
+fn draw_points(points: &HashSet<Point>) -> String {
+    let min_x = points.iter().map(|p| p.x).min().unwrap_or(0).min(0);
+    let max_x = points.iter().map(|p| p.x).max().unwrap_or(0).max(0);
+    let min_y = points.iter().map(|p| p.y).min().unwrap_or(0).min(0);
+    let max_y = points.iter().map(|p| p.y).max().unwrap_or(0).max(0);
+
+    let mut rows = Vec::new();
+    for y in min_y..=max_y {
+        let row: String = (min_x..=max_x)
+            .map(|x| match Point::new(x, y) {
+                Point::ORIGIN => 's',
+                p if points.contains(&p) => '#',
+                _ => '.',
+            })
+            .collect();
+        rows.push(row);
+    }
+    rows.join("\n")
+}
+
+/**
+ * Draws the path over the heightmap with arrows for each step, as in the
+ * puzzle description.
+ */
+fn draw_path(heightmap: &day12::Heightmap, path: &[Point]) -> String {
+    let mut canvas = heightmap.heights.map(|_| '.');
+    for step in path.windows(2) {
+        let arrow = match Direction::ALL.iter().find(|d| step[0] + d.vector() == step[1]) {
+            Some(Direction::Up) => '^',
+            Some(Direction::Down) => 'v',
+            Some(Direction::Left) => '<',
+            Some(Direction::Right) => '>',
+            None => '?',
+        };
+        canvas[step[0]] = arrow;
+    }
+    canvas[heightmap.end] = 'E';
+    canvas.to_string().trim_end().to_string()
+}
+
+pub(super) fn details(day: u8, input: &[String], timeout: Option<Duration>) -> Result<Details, String> {
+    let mut details = Details::new(timeout);
+    match day {
+        1 => {
+            let elves = day01::parse(input)?;
+            details.stat("Elves", elves.len());
+            details.stat("Food items", elves.iter().map(|e| e.calories.len()).sum::<usize>());
+        }
+        2 => details.stat("Rounds", day02::parse(input)?.len()),
+        3 => {
+            let rucksacks = day03::parse(input)?;
+            details.stat("Rucksacks", rucksacks.len());
+            details.stat("Items", rucksacks.iter().map(|r| r.len()).sum::<usize>());
+        }
+        4 => details.stat("Pairs", day04::parse(input)?.len()),
+        5 => {
+            let crates = day05::parse(input)?;
+            details.stat("Stacks", crates.stacks.len());
+            details.stat("Crates", crates.stacks.iter().map(|s| s.len()).sum::<usize>());
+            details.stat("Moves", crates.moves.len());
+        }
+        6 => details.stat("Datastream length", day06::parse(input)?.len()),
+        7 => {
+            let filesystem = day07::parse(input)?;
+            let directories = filesystem.root.walk();
+            details.stat("Files", directories.iter().map(|dir| dir.files.len()).sum::<usize>());
+            details.stat("Directories", directories.len() - 1);
+            details.stat("Used space", filesystem.root.size().map_err(|e| e.to_string())?);
+        }
+        8 => {
+            let forest = day08::parse(input)?;
+            details.stat("Grid", format!("{} × {}", forest.width(), forest.height()));
+        }
+        9 => {
+            let motions = day09::parse(input)?;
+            details.stat("Motions", motions.len());
+            details.stat("Steps", motions.iter().map(|m| m.steps as i64).sum::<i64>());
+            details.draw("Trail of the tail of the ten knot rope", || {
+                Some(draw_points(&day09::tail_trail(&motions, 10)))
+            });
+        }
+        10 => {
+            let program = day10::parse(input)?;
+            let cycles: usize = program
+                .iter()
+                .map(|i| match i {
+                    day10::Instruction::Noop => 1,
+                    day10::Instruction::Addx(_) => 2,
+                })
+                .sum();
+            details.stat("Instructions", program.len());
+            details.stat("Cycles", cycles);
+        }
+        11 => {
+            let monkeys = day11::parse(input)?;
+            details.stat("Monkeys", monkeys.len());
+            details.stat("Items", monkeys.iter().map(|m| m.items.len()).sum::<usize>());
+        }
+        12 => {
+            let heightmap = day12::parse(input)?;
+            details.stat("Grid", format!("{} × {}", heightmap.heights.width(), heightmap.heights.height()));
+            details.draw("Shortest path from S to E", || {
+                day12::shortest_path(&heightmap).map(|path| draw_path(&heightmap, &path))
+            });
+        }
+        13 => {
+            let pairs = day13::parse(input)?;
+            details.stat("Pairs", pairs.len());
+            details.stat("Packets", pairs.len() * 2);
+        }
+        14 => {
+            let paths = day14::parse(input)?;
+            details.stat("Rock paths", paths.len());
+            details.stat("Corners", paths.iter().map(|p| p.len()).sum::<usize>());
+            details.draw("Cave once sand falls into the abyss", || Some(day14::draw(&day14::pour_sand(&paths, false))));
+            details.draw("Cave once the source is blocked", || Some(day14::draw(&day14::pour_sand(&paths, true))));
+        }
+        15 => {
+            let sensors = day15::parse(input)?;
+            let beacons: HashSet<Point> = sensors.iter().map(|s| s.beacon).collect();
+            details.stat("Sensors", sensors.len());
+            details.stat("Beacons", beacons.len());
+        }
+        _ => {}
+    }
+    Ok(details)
+}
@@ -0,0 +1,140 @@
+//! Runs a registered solution, timing it and containing any panic.
+
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use crate::cancel::{self, Cancelled, Token};
+use crate::registry::{Solution, ZeroCopy};
+
+/**
+ * Why a solver produced no answer.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Failure {
+    /// The solver returned an error.
+    Error(String),
+    /// The solver panicked with this message.
+    Panicked(String),
+    /// The solver was stopped by its deadline or its cancellation token.
+    Stopped(Cancelled),
+}
+
+impl Failure {
+    pub fn is_timeout(&self) -> bool {
+        *self == Failure::Stopped(Cancelled::TimedOut)
+    }
+
+    pub fn is_panic(&self) -> bool {
+        matches!(self, Failure::Panicked(_))
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Failure::Error(message) => write!(f, "{}", message),
+            Failure::Panicked(message) => write!(f, "solver panicked: {}", message),
+            Failure::Stopped(reason) => write!(f, "solver {}", reason),
+        }
+    }
+}
+
+pub struct Outcome {
+    /// The answer, or why the solver failed.
+    pub answer: Result<String, Failure>,
+    pub elapsed: Duration,
+}
+
+/**
+ * Solves one part on the given input.
+ *
+ * A panicking solver is reported as an error instead of unwinding into the caller,
+ * just like one that returns an error.
+ */
+pub fn run(solution: &Solution, input: &[String]) -> Outcome {
+    run_with(solution, input, &Token::new())
+}
+
+/**
+ * Solves one part on the given input, stopping early once the token is
+ * cancelled or its deadline passes.
+ */
+pub fn run_with(solution: &Solution, input: &[String], token: &Token) -> Outcome {
+    contain(token, || (solution.solve)(input))
+}
+
+/**
+ * Like [`run_with`], for a part solved straight from the input text.
+ */
+pub fn run_zero_copy(solution: &ZeroCopy, text: &str, token: &Token) -> Outcome {
+    contain(token, || (solution.solve)(text))
+}
+
+/**
+ * Runs a solver under the token, timing it and catching its panics.
+ */
+fn contain(token: &Token, solve: impl FnOnce() -> Result<String, String>) -> Outcome {
+    let start = Instant::now();
+    let answer = guard(token, solve);
+    Outcome {
+        answer,
+        elapsed: start.elapsed(),
+    }
+}
+
+/**
+ * Runs `f` under the token and catches its panics the way a solver's are,
+ * for work done beside the solvers such as drawing the pictures of a report.
+ */
+pub fn guard<T>(token: &Token, f: impl FnOnce() -> Result<T, String>) -> Result<T, Failure> {
+    // cancel::run only stops the unwinding from a checkpoint, other panics pass through it
+    match panic::catch_unwind(AssertUnwindSafe(|| cancel::run(token, f))) {
+        Err(payload) => Err(Failure::Panicked(panic_message(payload.as_ref()))),
+        Ok(Err(reason)) => Err(Failure::Stopped(reason)),
+        Ok(Ok(result)) => result.map_err(Failure::Error),
+    }
+}
+
+/**
+ * The outcomes of every variant of a part on the same input.
+ */
+pub struct CrossCheck {
+    pub outcomes: Vec<(&'static Solution, Outcome)>,
+}
+
+impl CrossCheck {
+    /**
+     * Whether every variant gave the same answer, or failed the same way.
+     */
+    pub fn agrees(&self) -> bool {
+        self.outcomes.windows(2).all(|pair| pair[0].1.answer == pair[1].1.answer)
+    }
+}
+
+/**
+ * Runs each of the variants on the input, each with its own time limit.
+ */
+pub fn cross_check(variants: &[&'static Solution], input: &[String], timeout: Option<Duration>) -> CrossCheck {
+    let outcomes = variants
+        .iter()
+        .map(|&solution| {
+            let token = match timeout {
+                Some(timeout) => Token::with_timeout(timeout),
+                None => Token::new(),
+            };
+            (solution, run_with(solution, input, &token))
+        })
+        .collect();
+    CrossCheck { outcomes }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown cause".to_string()
+    }
+}
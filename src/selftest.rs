@@ -0,0 +1,173 @@
+//! Checks every solver against the example inputs and their expected answers.
+//!
+//! An example is a day's `YEAR/dayNN/test.txt` with the answers next to it in
+//! `test-part1.txt` and `test-part2.txt`. They are read from the source tree,
+//! or, in a binary built with the `embed-examples` feature, from copies
+//! compiled into it so that `aoc selftest` works without the sources.
+//!
+//! A part may have no expected answer, such as part 1 of 2022 day 15 whose
+//! row is fixed for the real input and so says nothing on the example.
+
+use std::path::Path;
+use std::time::Duration;
+
+use crate::cancel::Token;
+use crate::registry;
+use crate::runner;
+use crate::util::input::puzzle_path;
+
+/**
+ * A day's example input and the expected answer of each part.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub year: u16,
+    pub day: u8,
+    pub input: String,
+    pub answers: Vec<(u8, String)>,
+}
+
+/**
+ * The answer as stored in its file, without the final newline.
+ */
+fn answer_from_file(text: &str) -> String {
+    text.strip_suffix('\n').unwrap_or(text).to_string()
+}
+
+/**
+ * Reads the examples of every implemented day under `root`, skipping the days
+ * without one.
+ */
+pub fn load(root: &Path) -> Vec<Example> {
+    let mut days: Vec<(u16, u8)> = registry::all().map(|s| (s.year, s.day)).collect();
+    days.dedup();
+
+    let mut examples = Vec::new();
+    for (year, day) in days {
+        let Ok(input) = std::fs::read_to_string(root.join(puzzle_path(year, day, "test.txt"))) else {
+            continue;
+        };
+        let mut answers = Vec::new();
+        for part in [1, 2] {
+            let path = root.join(puzzle_path(year, day, &format!("test-part{}.txt", part)));
+            if let Ok(answer) = std::fs::read_to_string(path) {
+                answers.push((part, answer_from_file(&answer)));
+            }
+        }
+        examples.push(Example { year, day, input, answers });
+    }
+    examples
+}
+
+/**
+ * Compiles in a day's example and answers, given its directory such as
+ * `2022/day01` and the parts with an answer if not both.
+ */
+#[cfg(feature = "embed-examples")]
+macro_rules! embed {
+    ($dir:literal) => {
+        embed!($dir, 1, 2)
+    };
+    ($dir:literal, $($part:literal),+) => {
+        (
+            $dir,
+            include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $dir, "/test.txt")),
+            &[$(($part, include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/", $dir, "/test-part", $part, ".txt")))),+],
+        )
+    };
+}
+
+/**
+ * A day's directory, example and the expected answer of each part it has one
+ * for.
+ */
+#[cfg(feature = "embed-examples")]
+type Embedded = (&'static str, &'static str, &'static [(u8, &'static str)]);
+
+#[cfg(feature = "embed-examples")]
+const EMBEDDED: &[Embedded] = &[
+    embed!("2022/day01"),
+    embed!("2022/day02"),
+    embed!("2022/day03"),
+    embed!("2022/day04"),
+    embed!("2022/day05"),
+    embed!("2022/day06"),
+    embed!("2022/day07"),
+    embed!("2022/day08"),
+    embed!("2022/day09"),
+    embed!("2022/day10"),
+    embed!("2022/day11"),
+    embed!("2022/day12"),
+    embed!("2022/day13"),
+    embed!("2022/day14"),
+    embed!("2022/day15", 2),
+];
+
+/**
+ * The examples compiled into the binary, `None` unless it was built with the
+ * `embed-examples` feature.
+ */
+#[cfg(feature = "embed-examples")]
+pub fn embedded() -> Option<Vec<Example>> {
+    let examples = EMBEDDED
+        .iter()
+        .map(|&(dir, input, answers)| {
+            let (year, day) = dir.split_once("/day").unwrap();
+            Example {
+                year: year.parse().unwrap(),
+                day: day.parse().unwrap(),
+                input: input.to_string(),
+                answers: answers.iter().map(|&(part, answer)| (part, answer_from_file(answer))).collect(),
+            }
+        })
+        .collect();
+    Some(examples)
+}
+
+#[cfg(not(feature = "embed-examples"))]
+pub fn embedded() -> Option<Vec<Example>> {
+    None
+}
+
+/**
+ * The outcome of one variant of one part on its example.
+ */
+pub struct Check {
+    pub solution: &'static registry::Solution,
+    pub expected: String,
+    pub actual: Result<String, String>,
+    pub elapsed: Duration,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        self.actual.as_ref() == Ok(&self.expected)
+    }
+}
+
+/**
+ * Runs every variant of every part with an expected answer on its example,
+ * each within `timeout` if given.
+ */
+pub fn run(examples: &[Example], timeout: Option<Duration>) -> Vec<Check> {
+    let mut checks = Vec::new();
+    for example in examples {
+        let lines: Vec<String> = example.input.lines().map(|s| s.to_string()).collect();
+        for (part, expected) in &example.answers {
+            for solution in registry::variants(example.year, example.day, *part) {
+                let token = match timeout {
+                    Some(timeout) => Token::with_timeout(timeout),
+                    None => Token::new(),
+                };
+                let outcome = runner::run_with(solution, &lines, &token);
+                checks.push(Check {
+                    solution,
+                    expected: expected.clone(),
+                    actual: outcome.answer.map_err(|failure| failure.to_string()),
+                    elapsed: outcome.elapsed,
+                });
+            }
+        }
+    }
+    checks
+}
@@ -0,0 +1,211 @@
+//! A small localhost HTTP API over the registry.
+//!
+//! `POST /{year}/day/{day}/part/{part}` with the puzzle input as the body
+//! answers with a JSON object holding the answer (or error) and the time taken.
+//! A solver that runs past its timeout is stopped and answered with a 504.
+
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::registry;
+use crate::cancel::Token;
+use crate::runner;
+
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+/**
+ * The body answering a request that named a part, solved or not.
+ */
+#[derive(Serialize)]
+struct Solved {
+    year: u16,
+    day: u8,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    time_us: u128,
+}
+
+/**
+ * The body answering a request that could not be served.
+ */
+#[derive(Serialize)]
+struct Rejected<'a> {
+    error: &'a str,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Response {
+        match serde_json::to_string(body) {
+            Ok(body) => Response { status, body },
+            Err(e) => Response::error(500, &e.to_string()),
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Response {
+        Response::json(status, &Rejected { error: message })
+    }
+
+    fn reason(&self) -> &'static str {
+        match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            504 => "Gateway Timeout",
+            _ => "Internal Server Error",
+        }
+    }
+}
+
+/**
+ * Largest request body that will be read, in bytes.
+ */
+const MAX_BODY_LEN: usize = 64 * 1024 * 1024;
+
+/**
+ * How long a solver may run when the server is not told otherwise.
+ */
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/**
+ * Splits `/{year}/day/{day}/part/{part}` into its numbers.
+ */
+fn parse_route(path: &str) -> Option<(u16, u8, u8)> {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    match segments[..] {
+        [year, "day", day, "part", part] => {
+            Some((year.parse().ok()?, day.parse().ok()?, part.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+/**
+ * Answers a single request. This is independent of any socket so it can be
+ * exercised directly.
+ */
+pub fn handle(method: &str, path: &str, body: &str) -> Response {
+    handle_within(method, path, body, DEFAULT_TIMEOUT)
+}
+
+/**
+ * Answers a single request, giving the solver at most `timeout` to finish.
+ */
+pub fn handle_within(method: &str, path: &str, body: &str, timeout: Duration) -> Response {
+    let (year, day, part) = match parse_route(path) {
+        Some(route) => route,
+        None => return Response::error(404, "expected /{year}/day/{day}/part/{part}"),
+    };
+    if method != "POST" {
+        return Response::error(405, "use POST with the puzzle input as the body");
+    }
+    let solution = match registry::find(year, day, part) {
+        Some(solution) => solution,
+        None => {
+            let message = format!("{} day {} part {} is not implemented", year, day, part);
+            return Response::error(404, &message);
+        }
+    };
+
+    let input: Vec<String> = body.lines().map(|s| s.to_string()).collect();
+    let outcome = runner::run_with(solution, &input, &Token::with_timeout(timeout));
+    let status = match &outcome.answer {
+        Ok(_) => 200,
+        Err(failure) if failure.is_timeout() => 504,
+        Err(_) => 422,
+    };
+    let (answer, error) = match outcome.answer {
+        Ok(answer) => (Some(answer), None),
+        Err(failure) => (None, Some(failure.to_string())),
+    };
+    let body = Solved {
+        year,
+        day,
+        part,
+        answer,
+        error,
+        time_us: outcome.elapsed.as_micros(),
+    };
+    Response::json(status, &body)
+}
+
+/**
+ * Reads one HTTP/1.1 request from the stream and writes the response.
+ * Every connection is closed after a single exchange.
+ */
+fn handle_connection(stream: TcpStream, timeout: Duration) -> io::Result<()> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or("").to_string();
+    let path = parts.next().unwrap_or("").to_string();
+
+    // headers, we only care about the body length
+    let mut content_length = 0;
+    loop {
+        let mut header = String::new();
+        if reader.read_line(&mut header)? == 0 || header.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.eq_ignore_ascii_case("content-length") {
+                content_length = value.trim().parse().unwrap_or(0);
+            }
+        }
+    }
+
+    let response = if method.is_empty() || path.is_empty() {
+        Response::error(400, "malformed request line")
+    } else if content_length > MAX_BODY_LEN {
+        Response::error(413, "request body too large")
+    } else {
+        let mut body = vec![0; content_length];
+        reader.read_exact(&mut body)?;
+        match String::from_utf8(body) {
+            Ok(body) => handle_within(&method, &path, &body, timeout),
+            Err(_) => Response::error(400, "request body is not valid UTF-8"),
+        }
+    };
+
+    let mut stream = stream;
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        response.reason(),
+        response.body.len(),
+        response.body
+    )?;
+    stream.flush()
+}
+
+/**
+ * Serves requests on the listener forever, one thread per connection, with
+ * every solver limited to `timeout`. A connection that fails is dropped and
+ * its error passed to `on_error`.
+ */
+pub fn serve(listener: TcpListener, timeout: Duration, on_error: fn(io::Error)) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let stream = stream?;
+        thread::spawn(move || {
+            if let Err(e) = handle_connection(stream, timeout) {
+                on_error(e);
+            }
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Shrinks a failing input to a small reproducer by delta debugging.
+//!
+//! The input is cut into pieces, first the blocks between blank lines (an
+//! Elf's food, a monkey, a pair of packets), then single lines and, for one
+//! line inputs such as day 6, single characters. Pieces are removed for as
+//! long as the input still fails, ending with an input from which no single
+//! piece can be removed.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::ledger::sha256_hex;
+use crate::registry::{self, Solution};
+use crate::runner;
+use crate::util::input::puzzle_path;
+
+/**
+ * How long each solver may take on a candidate input.
+ */
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(2);
+
+/**
+ * What makes an input fail.
+ */
+#[derive(Clone, Copy)]
+pub enum Property {
+    /// The variants of a part give different answers.
+    Disagreement { year: u16, day: u8, part: u8 },
+    /// The solver panics.
+    Panic(&'static Solution),
+}
+
+impl Property {
+    /**
+     * Whether the input still fails, giving each solver run `timeout`.
+     */
+    pub fn fails(&self, input: &[String], timeout: Duration) -> bool {
+        match *self {
+            Property::Disagreement { year, day, part } => {
+                let variants: Vec<_> = registry::variants(year, day, part).collect();
+                !runner::cross_check(&variants, input, Some(timeout)).agrees()
+            }
+            Property::Panic(solution) => {
+                let outcome = runner::cross_check(&[solution], input, Some(timeout)).outcomes.remove(0).1;
+                outcome.answer.is_err_and(|failure| failure.is_panic())
+            }
+        }
+    }
+}
+
+/**
+ * Removes pieces from `pieces` while `fails` holds, trying ever smaller
+ * chunks down to single pieces (ddmin, removing complements only).
+ */
+pub fn ddmin<T: Clone>(mut pieces: Vec<T>, fails: &mut impl FnMut(&[T]) -> bool) -> Vec<T> {
+    let mut chunks = 2;
+    while pieces.len() >= 2 {
+        let chunk_size = pieces.len().div_ceil(chunks);
+        let mut removed = false;
+        for start in (0..pieces.len()).step_by(chunk_size) {
+            let end = (start + chunk_size).min(pieces.len());
+            let candidate: Vec<T> = pieces[..start].iter().chain(&pieces[end..]).cloned().collect();
+            if fails(&candidate) {
+                pieces = candidate;
+                chunks = (chunks - 1).max(2);
+                removed = true;
+                break;
+            }
+        }
+        if !removed {
+            if chunks >= pieces.len() {
+                break;
+            }
+            chunks = (chunks * 2).min(pieces.len());
+        }
+    }
+    pieces
+}
+
+/**
+ * Splits the lines into the blocks between blank lines.
+ */
+fn blocks(input: &[String]) -> Vec<Vec<String>> {
+    input
+        .split(|line| line.trim().is_empty())
+        .filter(|block| !block.is_empty())
+        .map(|block| block.to_vec())
+        .collect()
+}
+
+fn join_blocks(blocks: &[Vec<String>]) -> Vec<String> {
+    blocks.join(&String::new())
+}
+
+/**
+ * Shrinks an input that fails, as decided by `fails`, to one that still does.
+ */
+pub fn minimize(input: &[String], mut fails: impl FnMut(&[String]) -> bool) -> Vec<String> {
+    let mut input = input.to_vec();
+
+    // blocks are joined by a single blank line, which must not hide the failure
+    let groups = blocks(&input);
+    if groups.len() > 1 && fails(&join_blocks(&groups)) {
+        let groups = ddmin(groups, &mut |groups: &[Vec<String>]| fails(&join_blocks(groups)));
+        input = join_blocks(&groups);
+    }
+
+    input = ddmin(input, &mut fails);
+
+    if let [line] = &input[..] {
+        let chars = ddmin(line.chars().collect(), &mut |chars: &[char]| fails(&[chars.iter().collect()]));
+        input = vec![chars.into_iter().collect()];
+    }
+    input
+}
+
+/**
+ * Where a reproducer is kept: `YEAR/dayNN/cases/NAME.txt` under `root`.
+ */
+pub fn case_path(root: &Path, year: u16, day: u8, name: &str) -> PathBuf {
+    root.join(puzzle_path(year, day, "cases")).join(format!("{}.txt", name))
+}
+
+/**
+ * Saves a reproducer among the day's test cases, named after its content so
+ * that saving the same one twice keeps a single file.
+ */
+pub fn save_case(root: &Path, year: u16, day: u8, input: &[String]) -> std::io::Result<PathBuf> {
+    let mut text = input.join("\n");
+    text.push('\n');
+    let path = case_path(root, year, day, &format!("shrunk-{}", &sha256_hex(text.as_bytes())[..12]));
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, text)?;
+    Ok(path)
+}
@@ -0,0 +1,254 @@
+//! Step-by-step simulations that can be paused, rewound and resumed.
+//!
+//! Days whose answer comes out of a simulation (the rope, the CPU, the monkeys'
+//! rounds, the falling sand) implement [`Simulation`]. Their parts simply run
+//! it to the end, while a [`Session`] lets the CLI go one step at a time, go
+//! back by replaying from where it started, and save a [`Checkpoint`] of a
+//! long run to disk to resume it later.
+
+use std::fmt::{self, Display};
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::cancel;
+use crate::ledger::sha256_hex;
+
+/**
+ * A simulation advancing in discrete steps.
+ */
+pub trait Simulation {
+    /// Everything that changes while the simulation runs, enough to resume it.
+    type State: Clone + Serialize + DeserializeOwned;
+    /// Why a step may fail, `Infallible` for most simulations.
+    type Error: Display;
+
+    /**
+     * Advances by one step. Returns false, changing nothing, once the
+     * simulation is over.
+     */
+    fn step(&mut self) -> Result<bool, Self::Error>;
+
+    fn state(&self) -> &Self::State;
+
+    /**
+     * Continues from a state taken from a simulation of the same input.
+     */
+    fn restore(&mut self, state: Self::State);
+
+    fn snapshot(&self) -> Self::State {
+        self.state().clone()
+    }
+
+    /**
+     * Draws the current state for a person to look at.
+     */
+    fn render(&self) -> String;
+
+    /**
+     * Steps until the simulation is over.
+     */
+    fn run(&mut self) -> Result<(), Self::Error> {
+        while self.step()? {
+            cancel::checkpoint();
+        }
+        Ok(())
+    }
+}
+
+/**
+ * A [`Simulation`] with its state as JSON, so that a session can hold any day's.
+ */
+trait Erased {
+    fn step(&mut self) -> Result<bool, String>;
+    fn render(&self) -> String;
+    fn save(&self) -> Result<Value, String>;
+    fn load(&mut self, state: Value) -> Result<(), String>;
+}
+
+impl<S: Simulation> Erased for S {
+    fn step(&mut self) -> Result<bool, String> {
+        Simulation::step(self).map_err(|e| e.to_string())
+    }
+
+    fn render(&self) -> String {
+        Simulation::render(self)
+    }
+
+    fn save(&self) -> Result<Value, String> {
+        serde_json::to_value(self.snapshot()).map_err(|e| e.to_string())
+    }
+
+    fn load(&mut self, state: Value) -> Result<(), String> {
+        let state = serde_json::from_value(state).map_err(|e| format!("invalid state: {}", e))?;
+        self.restore(state);
+        Ok(())
+    }
+}
+
+/**
+ * Which part's simulation a checkpoint belongs to and on which input.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Origin {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// SHA-256 of the input lines joined by newlines.
+    pub input: String,
+}
+
+impl Origin {
+    pub fn of(year: u16, day: u8, part: u8, input: &[String]) -> Origin {
+        Origin {
+            year,
+            day,
+            part,
+            input: sha256_hex(input.join("\n").as_bytes()),
+        }
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let input = self.input.get(..12).unwrap_or(&self.input);
+        write!(f, "{} day {} part {} on input {}", self.year, self.day, self.part, input)
+    }
+}
+
+/**
+ * The state of a simulation after some number of steps.
+ */
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Checkpoint {
+    pub origin: Origin,
+    pub steps: u64,
+    pub state: Value,
+}
+
+impl Checkpoint {
+    /**
+     * Writes the checkpoint as JSON, replacing the file only once it is
+     * complete so that an interrupted run keeps the previous checkpoint.
+     */
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let json = serde_json::to_string(self).map_err(|e| e.to_string())? + "\n";
+        let partial = path.with_extension("partial");
+        std::fs::write(&partial, json).map_err(|e| format!("cannot write '{}': {}", partial.display(), e))?;
+        std::fs::rename(&partial, path).map_err(|e| format!("cannot write '{}': {}", path.display(), e))
+    }
+
+    pub fn load(path: &Path) -> Result<Checkpoint, String> {
+        let json = std::fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path.display(), e))?;
+        serde_json::from_str(&json).map_err(|e| format!("'{}' is not a checkpoint: {}", path.display(), e))
+    }
+}
+
+/**
+ * A simulation being stepped through. Going back replays it from the state
+ * the session started from, which is the initial state unless it was resumed
+ * from a checkpoint.
+ */
+pub struct Session {
+    simulation: Box<dyn Erased>,
+    origin: Origin,
+    start: (u64, Value),
+    steps: u64,
+    finished: bool,
+}
+
+impl Session {
+    pub fn new(simulation: impl Simulation + 'static, origin: Origin) -> Result<Session, String> {
+        let start = simulation.save()?;
+        Ok(Session {
+            simulation: Box::new(simulation),
+            origin,
+            start: (0, start),
+            steps: 0,
+            finished: false,
+        })
+    }
+
+    /**
+     * The number of steps taken since the initial state.
+     */
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
+    /**
+     * Takes one step, returning false once the simulation is over.
+     */
+    pub fn step(&mut self) -> Result<bool, String> {
+        if self.finished {
+            return Ok(false);
+        }
+        if self.simulation.step()? {
+            self.steps += 1;
+        } else {
+            self.finished = true;
+        }
+        Ok(!self.finished)
+    }
+
+    /**
+     * Takes up to `limit` steps, or runs to the end without one, and returns
+     * how many were taken.
+     */
+    pub fn run(&mut self, limit: Option<u64>) -> Result<u64, String> {
+        let mut taken = 0;
+        while limit.is_none_or(|limit| taken < limit) && self.step()? {
+            cancel::checkpoint();
+            taken += 1;
+        }
+        Ok(taken)
+    }
+
+    /**
+     * Goes back `count` steps, but not before the state the session started
+     * from, and returns how many steps it went back.
+     */
+    pub fn back(&mut self, count: u64) -> Result<u64, String> {
+        let (start_steps, start) = self.start.clone();
+        let target = self.steps.saturating_sub(count).max(start_steps);
+        let went_back = self.steps - target;
+        self.simulation.load(start)?;
+        self.steps = start_steps;
+        self.finished = false;
+        self.run(Some(target - start_steps))?;
+        Ok(went_back)
+    }
+
+    pub fn render(&self) -> String {
+        self.simulation.render()
+    }
+
+    pub fn checkpoint(&self) -> Result<Checkpoint, String> {
+        Ok(Checkpoint {
+            origin: self.origin.clone(),
+            steps: self.steps,
+            state: self.simulation.save()?,
+        })
+    }
+
+    /**
+     * Continues from a checkpoint of the same part and input, which becomes
+     * the state the session started from.
+     */
+    pub fn resume(&mut self, checkpoint: Checkpoint) -> Result<(), String> {
+        if checkpoint.origin != self.origin {
+            return Err(format!("the checkpoint is of {}, not of {}", checkpoint.origin, self.origin));
+        }
+        self.simulation.load(checkpoint.state.clone())?;
+        self.start = (checkpoint.steps, checkpoint.state);
+        self.steps = checkpoint.steps;
+        self.finished = false;
+        Ok(())
+    }
+}
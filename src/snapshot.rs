@@ -0,0 +1,163 @@
+//! Snapshot tests for text renderings.
+//!
+//! Every rendering the report makes of a day's example input is stored under
+//! `YEAR/dayNN/snapshots/<title>.txt`. Checking compares the current
+//! renderings with those files, and updating rewrites them after an
+//! intentional change; `aoc snapshots --update-snapshots` or running the tests
+//! with `UPDATE_SNAPSHOTS=1` does that.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::report;
+use crate::util::input::puzzle_path;
+
+/**
+ * Set to anything but `0` to update snapshots instead of checking them.
+ */
+pub const UPDATE_ENV: &str = "UPDATE_SNAPSHOTS";
+
+pub struct Snapshot {
+    pub name: String,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Check {
+    Matches,
+    /// The file was written, it was missing or differed.
+    Updated,
+    Missing,
+    /// The rendering differs from the file, see [`diff`].
+    Changed { diff: String },
+}
+
+/**
+ * Whether the environment asks for snapshots to be updated.
+ */
+pub fn update_from_env() -> bool {
+    std::env::var_os(UPDATE_ENV).is_some_and(|v| v != "0")
+}
+
+/**
+ * Turns a picture title into a file name, e.g. `part-2-answer`.
+ */
+fn slug(title: &str) -> String {
+    let mut slug = String::new();
+    for c in title.chars() {
+        if c.is_ascii_alphanumeric() {
+            slug.push(c.to_ascii_lowercase());
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/**
+ * The renderings of a day for the given input, named after their titles.
+ */
+pub fn renderings(year: u16, day: u8, input: &str) -> Vec<Snapshot> {
+    report::day(year, day, input, None)
+        .renderings()
+        .into_iter()
+        .map(|picture| Snapshot {
+            name: slug(&picture.title),
+            text: picture.text,
+        })
+        .collect()
+}
+
+/**
+ * Where a snapshot of a day is stored, relative to `root`.
+ */
+pub fn path(root: &Path, year: u16, day: u8, name: &str) -> PathBuf {
+    root.join(puzzle_path(year, day, "snapshots")).join(format!("{}.txt", name))
+}
+
+/**
+ * Compares the text with the stored snapshot, or stores it when `update` is set.
+ */
+pub fn check(path: &Path, actual: &str, update: bool) -> io::Result<Check> {
+    let expected = match fs::read_to_string(path) {
+        Ok(expected) => Some(expected),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+        Err(e) => return Err(e),
+    };
+    // files end with a newline, renderings usually don't
+    let actual = format!("{}\n", actual.trim_end_matches('\n'));
+
+    match expected {
+        Some(expected) if expected == actual => Ok(Check::Matches),
+        _ if update => {
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(path, actual)?;
+            Ok(Check::Updated)
+        }
+        None => Ok(Check::Missing),
+        Some(expected) => Ok(Check::Changed {
+            diff: diff(&expected, &actual),
+        }),
+    }
+}
+
+/**
+ * Lines of unchanged context kept around every change.
+ */
+const CONTEXT: usize = 2;
+
+/**
+ * A line diff of two texts: removed lines start with `-`, added lines with `+`
+ * and unchanged ones with a space. Long unchanged stretches are elided.
+ */
+pub fn diff(expected: &str, actual: &str) -> String {
+    let old: Vec<&str> = expected.lines().collect();
+    let new: Vec<&str> = actual.lines().collect();
+
+    // lcs[i][j] is the longest common subsequence of old[i..] and new[j..]
+    let mut lcs = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push((' ', old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            lines.push(('-', old[i]));
+            i += 1;
+        } else {
+            lines.push(('+', new[j]));
+            j += 1;
+        }
+    }
+
+    let changed: Vec<usize> = (0..lines.len()).filter(|&k| lines[k].0 != ' ').collect();
+    let near_change = |k: usize| changed.iter().any(|&c| c.abs_diff(k) <= CONTEXT);
+
+    let mut out = String::new();
+    let mut elided = false;
+    for (k, (mark, line)) in lines.iter().enumerate() {
+        if near_change(k) {
+            out.push_str(&format!("{} {}\n", mark, line));
+            elided = false;
+        } else if !elided {
+            out.push_str("  ...\n");
+            elided = true;
+        }
+    }
+    out
+}
@@ -0,0 +1,307 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::util::lenient;
+use crate::util::point::Point;
+
+/**
+ * A position in a grid as (row, column).
+ */
+pub type Pos = (usize, usize);
+
+/**
+ * The four orthogonal steps as (row, column) deltas: up, down, left, right.
+ */
+pub const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/**
+ * The eight steps to the surrounding cells, orthogonal ones first.
+ */
+pub const SURROUNDING: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+/**
+ * A rectangular two dimensional grid stored row by row.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /**
+     * Creates a grid with every cell set to `fill`.
+     */
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /**
+     * Builds a grid from its rows, which must all have the same length.
+     */
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Grid<T>, String> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(format!(
+                    "row {} has {} cells, expected {}",
+                    i + 1,
+                    row.len(),
+                    width
+                ));
+            }
+            cells.extend(row);
+        }
+        Ok(Grid { width, height, cells })
+    }
+
+    /**
+     * Parses one row per line, converting every character with `f`. A lenient
+     * parse skips the rows with a bad cell or a width other than the first
+     * row's.
+     */
+    pub fn parse<S: AsRef<str>>(
+        lines: &[S],
+        mut f: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Grid<T>, String> {
+        let mut rows: Vec<Vec<T>> = Vec::with_capacity(lines.len());
+        for (i, line) in lines.iter().enumerate() {
+            let row = match line.as_ref().chars().map(&mut f).collect::<Result<Vec<T>, String>>() {
+                Ok(row) => row,
+                Err(e) => {
+                    lenient::skip(i, e)?;
+                    continue;
+                }
+            };
+            if let Some(first) = rows.first().filter(|first| first.len() != row.len()) {
+                if lenient::is_lenient() {
+                    lenient::skip(i, format!("row has {} cells, expected {}", row.len(), first.len()))?;
+                    continue;
+                }
+            }
+            rows.push(row);
+        }
+        Grid::from_rows(rows)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.width + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /**
+     * Looks up a cell by point, `None` if the point lies outside the grid.
+     */
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.get(point.to_pos()?)
+    }
+
+    /**
+     * Moves one step from `pos`, returning `None` if that leaves the grid.
+     */
+    pub fn step(&self, (row, col): Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let next = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+        if self.contains(next) {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /**
+     * All positions, row by row.
+     */
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let width = self.width;
+        (0..self.width * self.height).map(move |i| (i / width, i % width))
+    }
+
+    /**
+     * All cells with their positions, row by row.
+     */
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /**
+     * The position of the first cell, row by row, that satisfies `pred`.
+     */
+    pub fn position(&self, mut pred: impl FnMut(&T) -> bool) -> Option<Pos> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    /**
+     * The in-bounds orthogonal neighbours of `pos`.
+     */
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        ORTHOGONAL.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /**
+     * The in-bounds orthogonal and diagonal neighbours of `pos`.
+     */
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        SURROUNDING.iter().filter_map(move |&d| self.step(pos, d))
+    }
+
+    /**
+     * The positions reached by repeatedly stepping in `direction` from `pos`
+     * (exclusive) until the edge of the grid.
+     */
+    pub fn ray(&self, pos: Pos, direction: (isize, isize)) -> impl Iterator<Item = Pos> + '_ {
+        std::iter::successors(self.step(pos, direction), move |&p| self.step(p, direction))
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // chunks() rejects a zero width, an empty grid has no rows anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.width.max(1)).take(self.height)
+    }
+
+    /**
+     * Converts every cell with `f`, keeping the shape.
+     */
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+/**
+ * A grid is written as its list of rows.
+ */
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.rows())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Grid<T>, D::Error> {
+        let rows = Vec::<Vec<T>>::deserialize(deserializer)?;
+        Grid::from_rows(rows).map_err(D::Error::custom)
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        assert!(
+            self.contains(pos),
+            "{:?} out of bounds for a {}x{} grid",
+            pos,
+            self.width,
+            self.height
+        );
+        &self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        assert!(
+            self.contains(pos),
+            "{:?} out of bounds for a {}x{} grid",
+            pos,
+            self.width,
+            self.height
+        );
+        &mut self.cells[pos.0 * self.width + pos.1]
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match point.to_pos() {
+            Some(pos) => &self[pos],
+            None => panic!("{} out of bounds for a {}x{} grid", point, self.width, self.height),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        match point.to_pos() {
+            Some(pos) => &mut self[pos],
+            None => panic!("{} out of bounds for a {}x{} grid", point, width, height),
+        }
+    }
+}
+
+/**
+ * Prints one line per row with the cells next to each other.
+ */
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.rows().take(self.height) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
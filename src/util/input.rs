@@ -0,0 +1,60 @@
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use memmap2::Mmap;
+
+/**
+ * Reads the whole file and splits it into lines.
+ */
+pub fn read_lines(filename: impl AsRef<Path>) -> io::Result<Vec<String>> {
+    let contents = std::fs::read_to_string(filename)?;
+    Ok(contents.lines().map(|s| s.to_string()).collect())
+}
+
+/**
+ * Returns the conventional location of a puzzle file, e.g. `2022/day07/input.txt`.
+ */
+pub fn puzzle_path(year: u16, day: u8, file_name: &str) -> PathBuf {
+    PathBuf::from(year.to_string())
+        .join(format!("day{:02}", day))
+        .join(file_name)
+}
+
+/**
+ * A puzzle input mapped into memory instead of read, for inputs too large to
+ * copy around. The days that can parse it in place borrow its lines, see
+ * [`registry::zero_copy_for`].
+ *
+ * [`registry::zero_copy_for`]: crate::registry::zero_copy_for
+ */
+pub struct MappedInput {
+    /// `None` for an empty file, which cannot be mapped.
+    map: Option<Mmap>,
+}
+
+impl MappedInput {
+    pub fn open(path: impl AsRef<Path>) -> Result<MappedInput, String> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| format!("cannot read '{}': {}", path.display(), e))?;
+        let length = file.metadata().map_err(|e| format!("cannot read '{}': {}", path.display(), e))?.len();
+        if length == 0 {
+            return Ok(MappedInput { map: None });
+        }
+        // SAFETY: the mapping is only read, and like any input file it must not
+        // be changed while a puzzle is solved
+        let map = unsafe { Mmap::map(&file) }.map_err(|e| format!("cannot map '{}': {}", path.display(), e))?;
+        Ok(MappedInput { map: Some(map) })
+    }
+
+    pub fn bytes(&self) -> &[u8] {
+        self.map.as_deref().unwrap_or_default()
+    }
+
+    /**
+     * The input as text, checked to be UTF-8 but not copied.
+     */
+    pub fn text(&self) -> Result<&str, String> {
+        std::str::from_utf8(self.bytes()).map_err(|e| format!("the input is not UTF-8: {}", e))
+    }
+}
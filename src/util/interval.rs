@@ -0,0 +1,227 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+/**
+ * An inclusive range of integers, never empty.
+ *
+ * It is written as its bounds, `2-4` in text and `[2, 4]` in JSON.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "(i64, i64)", into = "(i64, i64)")]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    /**
+     * The integers from `start` to `end`, both included, or `None` if `end`
+     * comes before `start`.
+     */
+    pub const fn new(start: i64, end: i64) -> Option<Interval> {
+        if end < start {
+            return None;
+        }
+        Some(Interval { start, end })
+    }
+
+    pub const fn point(x: i64) -> Interval {
+        Interval { start: x, end: x }
+    }
+
+    pub fn start(self) -> i64 {
+        self.start
+    }
+
+    pub fn end(self) -> i64 {
+        self.end
+    }
+
+    /**
+     * The number of integers in the interval, saturating for all of `i64`.
+     */
+    #[allow(clippy::len_without_is_empty)] // an interval is never empty
+    pub fn len(self) -> u64 {
+        self.end.abs_diff(self.start).saturating_add(1)
+    }
+
+    pub fn contains(self, x: i64) -> bool {
+        self.start <= x && x <= self.end
+    }
+
+    /**
+     * Whether every integer of `other` is in this interval.
+     */
+    pub fn covers(self, other: Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(self, other: Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    pub fn intersection(self, other: Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl TryFrom<(i64, i64)> for Interval {
+    type Error = String;
+
+    fn try_from((start, end): (i64, i64)) -> Result<Interval, String> {
+        Interval::new(start, end).ok_or_else(|| format!("the interval {}-{} ends before it starts", start, end))
+    }
+}
+
+impl From<Interval> for (i64, i64) {
+    fn from(interval: Interval) -> (i64, i64) {
+        (interval.start, interval.end)
+    }
+}
+
+impl fmt::Display for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/**
+ * A set of integers kept as the fewest intervals, in order and with gaps
+ * between them.
+ */
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> IntervalSet {
+        IntervalSet::default()
+    }
+
+    /**
+     * The intervals of the set, in order.
+     */
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /**
+     * The number of integers in the set, saturating for all of `i64`.
+     */
+    pub fn len(&self) -> u64 {
+        self.intervals.iter().fold(0, |total: u64, interval| total.saturating_add(interval.len()))
+    }
+
+    /**
+     * The first of the intervals that end at or after `x`.
+     */
+    fn first_ending_from(&self, x: i64) -> usize {
+        self.intervals.partition_point(|interval| interval.end < x)
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        self.intervals.get(self.first_ending_from(x)).is_some_and(|interval| interval.contains(x))
+    }
+
+    /**
+     * Whether every integer of `interval` is in the set.
+     */
+    pub fn covers(&self, interval: Interval) -> bool {
+        self.intervals
+            .get(self.first_ending_from(interval.start))
+            .is_some_and(|member| member.covers(interval))
+    }
+
+    /**
+     * Adds the integers of `interval`, merging it with the intervals it
+     * overlaps or touches.
+     */
+    pub fn insert(&mut self, interval: Interval) {
+        // the members from `first` up to `last` overlap or touch the interval
+        let first = self.intervals.partition_point(|member| member.end.saturating_add(1) < interval.start);
+        let last = self.intervals.partition_point(|member| member.start <= interval.end.saturating_add(1));
+        let mut merged = interval;
+        if first < last {
+            merged.start = merged.start.min(self.intervals[first].start);
+            merged.end = merged.end.max(self.intervals[last - 1].end);
+        }
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        let mut union = self.clone();
+        for &interval in &other.intervals {
+            union.insert(interval);
+        }
+        union
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(&a), Some(&b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            intervals.extend(a.intersection(b));
+            // the one ending first overlaps nothing further in the other set
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        IntervalSet { intervals }
+    }
+
+    /**
+     * The integers of this set that are not in `other`.
+     */
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        let mut intervals = Vec::new();
+        for &interval in &self.intervals {
+            let mut rest = Some(interval);
+            for &hole in &other.intervals[other.first_ending_from(interval.start)..] {
+                let Some(remaining) = rest.filter(|remaining| hole.start <= remaining.end) else {
+                    break;
+                };
+                if remaining.start < hole.start {
+                    intervals.push(Interval { start: remaining.start, end: hole.start - 1 });
+                }
+                rest = Interval::new(hole.end.saturating_add(1), remaining.end).filter(|_| hole.end < remaining.end);
+            }
+            intervals.extend(rest);
+        }
+        IntervalSet { intervals }
+    }
+
+    /**
+     * The integers missing between the first and the last interval, as the
+     * intervals in between.
+     */
+    pub fn gaps(&self) -> impl Iterator<Item = Interval> + '_ {
+        self.intervals.windows(2).map(|pair| Interval {
+            start: pair[0].end + 1,
+            end: pair[1].start - 1,
+        })
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> IntervalSet {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval);
+        }
+        set
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> IntervalSet {
+        IntervalSet { intervals: vec![interval] }
+    }
+}
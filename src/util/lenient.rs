@@ -0,0 +1,134 @@
+//! Tolerant parsing of hand-edited inputs.
+//!
+//! Parsers are strict: a malformed line fails the whole parse. Within [`run`]
+//! they are lenient instead, and the records they pass to [`skip`] or
+//! [`repair`] are dropped or fixed up with a [`Warning`], so that an input
+//! with a few broken lines still gets an answer. Like cancellation, the mode
+//! belongs to the thread so that `parse` keeps its plain signature:
+//!
+//! ```
+//! use aoc::util::lenient;
+//! use aoc::y2022::day04;
+//!
+//! let input = vec!["2-4,6-8".to_string(), "2-3 4-5".to_string()];
+//! assert!(day04::parse(&input).is_err());
+//!
+//! let (pairs, warnings) = lenient::run(|| day04::parse(&input));
+//! assert_eq!(pairs.unwrap().len(), 1);
+//! assert_eq!(warnings[0].line, 2);
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+
+/**
+ * What a lenient parser did with a malformed record.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    /// The record was left out.
+    Skipped,
+    /// The record was fixed up and kept.
+    Repaired,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Warning {
+    /// The line of the input, counting from 1.
+    pub line: usize,
+    pub action: Action,
+    pub message: String,
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let action = match self.action {
+            Action::Skipped => "skipped",
+            Action::Repaired => "repaired",
+        };
+        write!(f, "line {}: {}: {}", self.line, action, self.message)
+    }
+}
+
+thread_local! {
+    static WARNINGS: RefCell<Option<Vec<Warning>>> = const { RefCell::new(None) };
+}
+
+/**
+ * Runs `f` with lenient parsing on this thread and returns its result along
+ * with the warnings of the records skipped or repaired on the way.
+ */
+pub fn run<T>(f: impl FnOnce() -> T) -> (T, Vec<Warning>) {
+    /// Restores the previous mode even if `f` unwinds, e.g. from a cancelled solver.
+    struct Restore(Option<Vec<Warning>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            WARNINGS.with(|warnings| *warnings.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(WARNINGS.with(|warnings| warnings.replace(Some(Vec::new()))));
+    let result = f();
+    let warnings = WARNINGS.with(|warnings| warnings.borrow_mut().take()).unwrap_or_default();
+    (result, warnings)
+}
+
+pub fn is_lenient() -> bool {
+    WARNINGS.with(|warnings| warnings.borrow().is_some())
+}
+
+fn warn(index: usize, action: Action, message: String) -> Result<(), String> {
+    WARNINGS.with(|warnings| match warnings.borrow_mut().as_mut() {
+        Some(warnings) => {
+            warnings.push(Warning { line: index + 1, action, message });
+            Ok(())
+        }
+        None => Err(message),
+    })
+}
+
+/**
+ * Reports the malformed record at line `index` (counting from 0). A strict
+ * parse fails with `error`, a lenient one records a warning and gets `Ok`,
+ * after which the caller leaves the record out.
+ */
+pub fn skip(index: usize, error: String) -> Result<(), String> {
+    warn(index, Action::Skipped, error)
+}
+
+/**
+ * Like [`skip`], for a record the caller can fix up and keep in lenient mode.
+ */
+pub fn repair(index: usize, error: String) -> Result<(), String> {
+    warn(index, Action::Repaired, error)
+}
+
+/**
+ * Parses every line with `parse_line`, skipping the lines it rejects when
+ * lenient. The lines may be owned or borrowed, e.g. from a mapped input.
+ */
+pub fn parse_lines<T, S: AsRef<str>>(
+    input: impl IntoIterator<Item = S>,
+    mut parse_line: impl FnMut(&str) -> Result<T, String>,
+) -> Result<Vec<T>, String> {
+    let input = input.into_iter();
+    let mut records = Vec::with_capacity(input.size_hint().0);
+    for (i, line) in input.enumerate() {
+        match parse_line(line.as_ref()) {
+            Ok(record) => records.push(record),
+            Err(e) => skip(i, e)?,
+        }
+    }
+    Ok(records)
+}
+
+/**
+ * A one line summary of the warnings, e.g. `2 lines skipped, 1 repaired`.
+ */
+pub fn summary(warnings: &[Warning]) -> String {
+    let skipped = warnings.iter().filter(|w| w.action == Action::Skipped).count();
+    let repaired = warnings.len() - skipped;
+    let lines = if skipped == 1 { "line" } else { "lines" };
+    format!("{} {} skipped, {} repaired", skipped, lines, repaired)
+}
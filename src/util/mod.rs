@@ -0,0 +1,11 @@
+//! Helpers shared by every year's solutions.
+
+pub mod grid;
+pub mod input;
+pub mod interval;
+pub mod lenient;
+pub mod num;
+pub mod point;
+pub mod rng;
+pub mod scan;
+pub mod search;
@@ -0,0 +1,18 @@
+use std::fmt;
+
+/**
+ * An arithmetic operation whose result does not fit in its integer type.
+ *
+ * Solvers use checked arithmetic and return this instead of wrapping around,
+ * the message says what was being computed.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Overflow(pub &'static str);
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "arithmetic overflow while {}", self.0)
+    }
+}
+
+impl std::error::Error for Overflow {}
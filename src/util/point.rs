@@ -0,0 +1,223 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::grid::Pos;
+
+/**
+ * A point (or, as [`Vector`], a displacement) on the integer plane.
+ *
+ * Screen coordinates are used: `x` grows to the right and `y` grows downwards,
+ * so `y` is the row and `x` the column of a grid.
+ */
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+/**
+ * The difference between two points.
+ */
+pub type Vector = Point;
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+
+    pub const fn new(x: i64, y: i64) -> Point {
+        Point { x, y }
+    }
+
+    /**
+     * Clamps both components to -1, 0 or 1, i.e. a single step towards the
+     * direction this vector points in.
+     */
+    pub fn signum(self) -> Vector {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+
+    /**
+     * The taxicab distance, the number of orthogonal steps between the points.
+     */
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /**
+     * The chessboard distance, the number of king moves between the points.
+     */
+    pub fn chebyshev(self, other: Point) -> i64 {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
+    }
+
+    /**
+     * Converts to a grid position (row, column), or `None` for negative coordinates.
+     */
+    pub fn to_pos(self) -> Option<Pos> {
+        Some((usize::try_from(self.y).ok()?, usize::try_from(self.x).ok()?))
+    }
+}
+
+impl From<Pos> for Point {
+    fn from((row, col): Pos) -> Point {
+        Point::new(col as i64, row as i64)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Vector) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Vector) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, other: Point) -> Vector {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Vector) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i64> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: i64) -> Point {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+/**
+ * The four orthogonal directions.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+
+    /**
+     * Parses the usual one letter names `U`, `D`, `L` and `R`.
+     */
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' => Some(Direction::Up),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /**
+     * The one letter name, the inverse of [`Direction::from_char`].
+     */
+    pub fn to_char(self) -> char {
+        match self {
+            Direction::Up => 'U',
+            Direction::Down => 'D',
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        }
+    }
+
+    /**
+     * A single step in this direction.
+     */
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+            Direction::Right => Point::new(1, 0),
+        }
+    }
+}
+
+/**
+ * The eight compass directions, clockwise from north (up).
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Direction8 {
+    pub const ALL: [Direction8; 8] = [
+        Direction8::N,
+        Direction8::NE,
+        Direction8::E,
+        Direction8::SE,
+        Direction8::S,
+        Direction8::SW,
+        Direction8::W,
+        Direction8::NW,
+    ];
+
+    /**
+     * A single step in this direction.
+     */
+    pub fn vector(self) -> Vector {
+        match self {
+            Direction8::N => Point::new(0, -1),
+            Direction8::NE => Point::new(1, -1),
+            Direction8::E => Point::new(1, 0),
+            Direction8::SE => Point::new(1, 1),
+            Direction8::S => Point::new(0, 1),
+            Direction8::SW => Point::new(-1, 1),
+            Direction8::W => Point::new(-1, 0),
+            Direction8::NW => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Direction8 {
+        match direction {
+            Direction::Up => Direction8::N,
+            Direction::Down => Direction8::S,
+            Direction::Left => Direction8::W,
+            Direction::Right => Direction8::E,
+        }
+    }
+}
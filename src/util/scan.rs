@@ -0,0 +1,195 @@
+//! Extracting typed values from fixed-format lines.
+//!
+//! A pattern is literal text with `{}` placeholders, e.g.
+//! `"move {} from {} to {}"`. Each placeholder captures the text up to the
+//! literal that follows it (or the rest of the line for a trailing one), and
+//! the captures are converted to the requested types:
+//!
+//! ```
+//! use aoc::scan;
+//!
+//! let (num, src, dst): (u32, usize, usize) = scan!("move 3 from 1 to 2", "move {} from {} to {}").unwrap();
+//! assert_eq!((num, src, dst), (3, 1, 2));
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+/**
+ * Why a line didn't match a pattern.
+ */
+#[derive(Clone, PartialEq, Eq)]
+pub struct ScanError {
+    pub line: String,
+    pub pattern: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "\"{}\" does not match \"{}\": {}",
+            self.line, self.pattern, self.reason
+        )
+    }
+}
+
+impl fmt::Debug for ScanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl std::error::Error for ScanError {}
+
+/**
+ * A type a single capture can be converted to.
+ */
+pub trait Capture<'a>: Sized {
+    fn capture(s: &'a str) -> Option<Self>;
+}
+
+impl<'a> Capture<'a> for &'a str {
+    fn capture(s: &'a str) -> Option<Self> {
+        Some(s)
+    }
+}
+
+/**
+ * The result of a whole scan: a single capture or a tuple of them.
+ */
+pub trait Captures<'a>: Sized {
+    fn from_captures(captures: &[&'a str]) -> Result<Self, String>;
+}
+
+fn convert<'a, T: Capture<'a>>(captures: &[&'a str], i: usize) -> Result<T, String> {
+    T::capture(captures[i]).ok_or_else(|| {
+        format!(
+            "capture {} (\"{}\") is not a valid {}",
+            i + 1,
+            captures[i],
+            std::any::type_name::<T>()
+        )
+    })
+}
+
+fn expect_count(captures: &[&str], count: usize) -> Result<(), String> {
+    if captures.len() == count {
+        Ok(())
+    } else {
+        Err(format!(
+            "the pattern has {} placeholder(s) but {} value(s) were requested",
+            captures.len(),
+            count
+        ))
+    }
+}
+
+macro_rules! scalar_captures {
+    ($($t:ty),*) => {$(
+        impl<'a> Capture<'a> for $t {
+            fn capture(s: &'a str) -> Option<Self> {
+                <$t as FromStr>::from_str(s).ok()
+            }
+        }
+
+        impl<'a> Captures<'a> for $t {
+            fn from_captures(captures: &[&'a str]) -> Result<Self, String> {
+                expect_count(captures, 1)?;
+                convert(captures, 0)
+            }
+        }
+    )*};
+}
+
+scalar_captures!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64, char, bool, String);
+
+impl<'a> Captures<'a> for &'a str {
+    fn from_captures(captures: &[&'a str]) -> Result<Self, String> {
+        expect_count(captures, 1)?;
+        convert(captures, 0)
+    }
+}
+
+macro_rules! tuple_captures {
+    ($($count:literal => ($($t:ident $i:tt),+);)*) => {$(
+        impl<'a, $($t: Capture<'a>),+> Captures<'a> for ($($t,)+) {
+            fn from_captures(captures: &[&'a str]) -> Result<Self, String> {
+                expect_count(captures, $count)?;
+                Ok(($(convert::<$t>(captures, $i)?,)+))
+            }
+        }
+    )*};
+}
+
+tuple_captures! {
+    1 => (A 0);
+    2 => (A 0, B 1);
+    3 => (A 0, B 1, C 2);
+    4 => (A 0, B 1, C 2, D 3);
+    5 => (A 0, B 1, C 2, D 3, E 4);
+    6 => (A 0, B 1, C 2, D 3, E 4, F 5);
+}
+
+/**
+ * Matches the line against the pattern and returns the raw text of every
+ * placeholder.
+ */
+pub fn split<'a>(line: &'a str, pattern: &str) -> Result<Vec<&'a str>, String> {
+    let literals: Vec<&str> = pattern.split("{}").collect();
+
+    let mut rest = line
+        .strip_prefix(literals[0])
+        .ok_or_else(|| format!("expected \"{}\" at column 1", literals[0]))?;
+    let mut captures = Vec::new();
+
+    for (i, literal) in literals.iter().enumerate().skip(1) {
+        let column = line.len() - rest.len() + 1;
+        let end = if literal.is_empty() {
+            if i + 1 < literals.len() {
+                return Err("adjacent placeholders are ambiguous".to_string());
+            }
+            rest.len()
+        } else {
+            rest.find(literal)
+                .ok_or_else(|| format!("expected \"{}\" after column {}", literal, column))?
+        };
+        if end == 0 {
+            return Err(format!("capture {} at column {} is empty", i, column));
+        }
+        captures.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    if !rest.is_empty() {
+        return Err(format!("unexpected trailing \"{}\"", rest));
+    }
+    Ok(captures)
+}
+
+/**
+ * Matches the line against the pattern and converts the captures. Use the
+ * [`scan!`](crate::scan) macro rather than calling this directly.
+ */
+pub fn scan<'a, T: Captures<'a>>(line: &'a str, pattern: &str) -> Result<T, ScanError> {
+    split(line, pattern)
+        .and_then(|captures| T::from_captures(&captures))
+        .map_err(|reason| ScanError {
+            line: line.to_string(),
+            pattern: pattern.to_string(),
+            reason,
+        })
+}
+
+/**
+ * `scan!(line, "move {} from {} to {}")` matches a line against a pattern and
+ * yields its captures as a single value or a tuple of the inferred types, or a
+ * [`ScanError`](crate::util::scan::ScanError) describing the mismatch.
+ */
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr $(,)?) => {
+        $crate::util::scan::scan($line, $pattern)
+    };
+}
@@ -0,0 +1,192 @@
+//! Shortest paths through graphs given by a neighbour function.
+//!
+//! The states can be anything hashable, positions in a grid or whole puzzle
+//! configurations, and the graph is explored as far as needed instead of
+//! being built up front. Every search starts from any number of states and
+//! stops at the first goal it settles, or explores everything it can reach
+//! when no state is a goal:
+//!
+//! ```
+//! use aoc::util::search;
+//!
+//! // from 1, doubling or adding one, the fewest operations to reach 10
+//! let search = search::bfs([1], |&n: &u64| [n * 2, n + 1], |&n| n == 10);
+//! assert_eq!(search.goal, Some(10));
+//! assert_eq!(search.distance(&10), Some(4));
+//! assert_eq!(search.path(&10), Some(vec![1, 2, 4, 5, 10]));
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use crate::cancel;
+
+/**
+ * What a search found: the distance to every state it reached, the state each
+ * was reached from, and the goal it stopped at if any.
+ *
+ * Breadth first search knows a state's distance as soon as it queues it, so
+ * it includes states it never got to expand. The other searches only include
+ * the states they settled.
+ */
+#[derive(Clone, Debug)]
+pub struct Search<S> {
+    pub distances: HashMap<S, u64>,
+    pub previous: HashMap<S, S>,
+    pub goal: Option<S>,
+}
+
+impl<S: Hash + Eq + Clone> Search<S> {
+    fn new() -> Search<S> {
+        Search {
+            distances: HashMap::new(),
+            previous: HashMap::new(),
+            goal: None,
+        }
+    }
+
+    pub fn distance(&self, state: &S) -> Option<u64> {
+        self.distances.get(state).copied()
+    }
+
+    /**
+     * The distance to the goal the search stopped at.
+     */
+    pub fn goal_distance(&self) -> Option<u64> {
+        self.goal.as_ref().and_then(|goal| self.distance(goal))
+    }
+
+    /**
+     * The states along a shortest path from one of the starts to `state`,
+     * both included, or `None` if the search did not reach it.
+     */
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        self.distances.get(state)?;
+        let mut path = vec![state.clone()];
+        while let Some(previous) = self.previous.get(path.last().unwrap()) {
+            path.push(previous.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/**
+ * Breadth first search, every step costing one.
+ */
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = S>,
+{
+    let mut search = Search::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if !search.distances.contains_key(&start) {
+            search.distances.insert(start.clone(), 0);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(current) = queue.pop_front() {
+        cancel::checkpoint();
+        if is_goal(&current) {
+            search.goal = Some(current);
+            break;
+        }
+        let distance = search.distances[&current] + 1;
+        for next in neighbours(&current) {
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            search.distances.insert(next.clone(), distance);
+            search.previous.insert(next.clone(), current.clone());
+            queue.push_back(next);
+        }
+    }
+    search
+}
+
+/**
+ * Dijkstra's algorithm, `neighbours` giving each next state with the cost of
+ * the step to it.
+ */
+pub fn dijkstra<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    neighbours: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    astar(starts, neighbours, |_| 0, is_goal)
+}
+
+/**
+ * A* search, `heuristic` estimating the remaining cost to a goal. It must
+ * never overestimate, or the distances found may not be the shortest.
+ *
+ * Only the settled states, those taken from the queue, are in the distances.
+ */
+pub fn astar<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut neighbours: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> u64,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Search<S>
+where
+    S: Hash + Eq + Clone,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    let mut search = Search::new();
+    // the best known distance and predecessor of the states seen but not yet settled
+    let mut tentative: HashMap<S, (u64, Option<S>)> = HashMap::new();
+    // the queue holds indices into `states`, ties broken by the order they were queued in
+    let mut states = Vec::new();
+    let mut queue = BinaryHeap::new();
+    for start in starts {
+        if !tentative.contains_key(&start) {
+            tentative.insert(start.clone(), (0, None));
+            queue.push(Reverse((heuristic(&start), 0, states.len())));
+            states.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, distance, index))) = queue.pop() {
+        cancel::checkpoint();
+        let current = states[index].clone();
+        // a state queued again with a shorter distance was settled already
+        if search.distances.contains_key(&current) || tentative[&current].0 != distance {
+            continue;
+        }
+        let (_, previous) = tentative.remove(&current).unwrap();
+        search.distances.insert(current.clone(), distance);
+        if let Some(previous) = previous {
+            search.previous.insert(current.clone(), previous);
+        }
+        if is_goal(&current) {
+            search.goal = Some(current);
+            break;
+        }
+
+        for (next, cost) in neighbours(&current) {
+            let next_distance = distance.saturating_add(cost);
+            if search.distances.contains_key(&next) {
+                continue;
+            }
+            if tentative.get(&next).is_some_and(|&(known, _)| known <= next_distance) {
+                continue;
+            }
+            tentative.insert(next.clone(), (next_distance, Some(current.clone())));
+            queue.push(Reverse((next_distance.saturating_add(heuristic(&next)), next_distance, states.len())));
+            states.push(next);
+        }
+    }
+    search
+}
@@ -0,0 +1,89 @@
+use serde::{Deserialize, Serialize};
+
+use crate::util::lenient;
+use crate::util::num::Overflow;
+
+/**
+ * The calories of the food items one Elf carries.
+ */
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Elf {
+    pub calories: Vec<u64>,
+}
+
+impl Elf {
+    pub fn total(&self) -> Result<u64, Overflow> {
+        self.calories
+            .iter()
+            .try_fold(0u64, |sum, &n| sum.checked_add(n))
+            .ok_or(Overflow("summing an Elf's calories"))
+    }
+}
+
+/**
+ * Parses one calorie count per line, with a blank line between Elves.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Elf>, String> {
+    parse_lines(input.iter().map(|line| line.as_str()))
+}
+
+/**
+ * Same as [`parse`], borrowing the lines instead.
+ */
+pub fn parse_lines<'a>(input: impl IntoIterator<Item = &'a str>) -> Result<Vec<Elf>, String> {
+    let mut elves = vec![Elf::default()];
+    for (i, line) in input.into_iter().enumerate() {
+        if line.trim().is_empty() {
+            elves.push(Elf::default());
+            continue;
+        }
+        match line.trim().parse::<u64>() {
+            Ok(n) => elves.last_mut().unwrap().calories.push(n),
+            Err(e) => lenient::skip(i, format!("'{}' is not a calorie count: {}", line, e))?,
+        }
+    }
+    elves.retain(|elf| !elf.calories.is_empty());
+    Ok(elves)
+}
+
+/**
+ * Writes the calorie counts back, one Elf after another.
+ */
+pub fn to_text(elves: &[Elf]) -> String {
+    let elves: Vec<String> = elves
+        .iter()
+        .map(|elf| elf.calories.iter().map(|n| format!("{}\n", n)).collect())
+        .collect();
+    elves.join("\n")
+}
+
+/**
+ * Returns the most calories carried by a single Elf.
+ */
+pub fn part1(elves: &[Elf]) -> Result<Option<u64>, Overflow> {
+    let mut max_total = None;
+    for elf in elves {
+        let total = elf.total()?;
+        if max_total.is_none_or(|max| total > max) {
+            max_total = Some(total);
+        }
+    }
+    Ok(max_total)
+}
+
+/**
+ * Returns the calories carried by the top three Elves together, `None` if
+ * there are fewer than three.
+ */
+pub fn part2(elves: &[Elf]) -> Result<Option<u64>, Overflow> {
+    let mut totals = elves.iter().map(Elf::total).collect::<Result<Vec<u64>, Overflow>>()?;
+    if totals.len() < 3 {
+        return Ok(None);
+    }
+    totals.sort();
+    totals[totals.len() - 3..]
+        .iter()
+        .try_fold(0u64, |sum, &total| sum.checked_add(total))
+        .map(Some)
+        .ok_or(Overflow("summing the top three Elves"))
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+
+use crate::util::lenient;
+
+/**
+ * One round of the strategy guide: the opponent's shape (`A`, `B` or `C`) and
+ * the second column (`X`, `Y` or `Z`), which the two parts read differently.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Round {
+    pub opponent: char,
+    pub response: char,
+}
+
+/**
+ * Parses lines such as `A Y`.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Round>, String> {
+    parse_lines(input.iter().map(|line| line.as_str()))
+}
+
+/**
+ * Same as [`parse`], borrowing the lines instead.
+ */
+pub fn parse_lines<'a>(input: impl IntoIterator<Item = &'a str>) -> Result<Vec<Round>, String> {
+    let mut rounds = Vec::new();
+    for (i, line) in input.into_iter().enumerate() {
+        if let [opponent @ b'A'..=b'C', b' ', response @ b'X'..=b'Z'] = *line.as_bytes() {
+            rounds.push(Round { opponent: opponent as char, response: response as char });
+            continue;
+        }
+
+        // stray spaces and lower case letters can be fixed up, a missing choice cannot
+        let error = format!("'{}' is not a round of the strategy guide", line);
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let normalized: Vec<char> = words.join(" ").to_uppercase().chars().collect();
+        match normalized[..] {
+            [opponent @ 'A'..='C', ' ', response @ 'X'..='Z'] => {
+                lenient::repair(i, error)?;
+                rounds.push(Round { opponent, response });
+            }
+            _ => lenient::skip(i, error)?,
+        }
+    }
+    Ok(rounds)
+}
+
+pub fn to_text(rounds: &[Round]) -> String {
+    rounds.iter().map(|r| format!("{} {}\n", r.opponent, r.response)).collect()
+}
+
+/**
+ * Calculates the total score for the Rock Paper Scissors game according to the
+ * strategy guide.
+ *
+ * Win = 6 pts, Draw = 3 pts, Lose = 0pts.
+ * Rock (A/X) = 1 pts, Paper (B/Y) = 2pts, Scissors (C/Z) = 3pts.
+ */
+pub fn part1(rounds: &[Round]) -> i32 {
+    let mut total_score = 0;
+    for round in rounds {
+        let mut score = 0;
+
+        score += match (round.opponent, round.response) {
+            ('A', 'Y') | ('B', 'Z') | ('C', 'X') => 6, // win
+            ('A', 'X') | ('B', 'Y') | ('C', 'Z') => 3, // tie
+            ('A', 'Z') | ('B', 'X') | ('C', 'Y') => 0, // lose
+            _ => 0,
+        };
+
+        score += match round.response {
+            'X' => 1,
+            'Y' => 2,
+            'Z' => 3,
+            _ => 0,
+        };
+
+        total_score += score;
+    }
+    return total_score;
+}
+
+/**
+ * Calculates the total score for the Rock Paper Scissors game according to the
+ * strategy guide.
+ *
+ * Win (Z) = 6 pts, Draw (Y) = 3 pts, Lose (X) = 0pts.
+ * Rock (A) = 1 pts, Paper (B) = 2pts, Scissors (C) = 3pts.
+ */
+pub fn part2(rounds: &[Round]) -> i32 {
+    let mut total_score = 0;
+    for round in rounds {
+        let mut score = 0;
+        let outcome = round.response;
+
+        score += match (round.opponent, outcome) {
+            ('A', 'Y') | ('B', 'X') | ('C', 'Z') => 1, // we choose Rock
+            ('A', 'Z') | ('B', 'Y') | ('C', 'X') => 2, // we choose Paper
+            ('A', 'X') | ('B', 'Z') | ('C', 'Y') => 3, // we choose Scissors
+            _ => 0,
+        };
+
+        score += match outcome {
+            'Z' => 6,
+            'Y' => 3,
+            'X' => 0,
+            _ => 0,
+        };
+
+        total_score += score;
+    }
+    return total_score;
+}
@@ -1,19 +1,35 @@
-use std::fs::File;
-use std::path::Path;
-use std::io::prelude::*;
+use crate::util::lenient;
 
-fn read_lines(filename: impl AsRef<Path>) -> Vec<String> {
-    let mut file = File::open(filename).expect("File not found");
-    let mut contents = String::new();
-    file.read_to_string(&mut contents).expect("Error reading file");
-    contents.lines().map(|s| s.to_string()).collect()
+/**
+ * The items in one rucksack, one letter per item, split evenly between its
+ * two compartments.
+ */
+pub type Rucksack = String;
+
+/**
+ * Parses one rucksack per line.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Rucksack>, String> {
+    lenient::parse_lines(input, |line| {
+        if !line.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(format!("'{}' contains an item that is not a letter", line));
+        }
+        if line.len() % 2 != 0 {
+            return Err(format!("'{}' cannot be split into two equal compartments", line));
+        }
+        Ok(line.to_string())
+    })
+}
+
+pub fn to_text(rucksacks: &[Rucksack]) -> String {
+    rucksacks.iter().map(|r| format!("{}\n", r)).collect()
 }
 
 /**
  * Separate the input string in the middle and return the item (char) that
  * exists in both parts.
  */
-fn find_repeated_item(input: &String) -> char {
+fn find_repeated_item(input: &str) -> char {
     let first_half = &input[0..input.len() / 2];
     let second_half = &input[input.len() / 2..input.len()];
     for c in first_half.chars() {
@@ -43,9 +59,9 @@ fn get_priority(c: char) -> i32 {
 /**
  * Calculates the sum of priorities for all the repeated items in each line.
  */
-fn part1(input: &Vec<String>) -> i32 {
+pub fn part1(rucksacks: &[Rucksack]) -> i32 {
     let mut total_priorities = 0;
-    for line in input {
+    for line in rucksacks {
         let repeated_item = find_repeated_item(line);
         if repeated_item != ' ' {
             total_priorities += get_priority(repeated_item);
@@ -57,7 +73,7 @@ fn part1(input: &Vec<String>) -> i32 {
 /**
  * Returns the item (char) that exists in all three input strings.
  */
-fn find_common_item(in1: &String, in2: &String, in3: &String) -> char {
+fn find_common_item(in1: &str, in2: &str, in3: &str) -> char {
     for c in in1.chars() {
         if in2.contains(c) && in3.contains(c) {
             return c;
@@ -68,25 +84,24 @@ fn find_common_item(in1: &String, in2: &String, in3: &String) -> char {
 
 /**
  * Calculates the sum of priorities for all the common items in every 3 lines.
+ *
+ * Fails if the rucksacks cannot be split into groups of three.
  */
-fn part2(input: &Vec<String>) -> i32 {
+pub fn part2(rucksacks: &[Rucksack]) -> Result<i32, String> {
+    if !rucksacks.len().is_multiple_of(3) {
+        return Err(format!("{} rucksacks cannot be split into groups of three", rucksacks.len()));
+    }
     let mut index = 0;
     let mut total_priorities = 0;
-    while index < input.len() {
-        let in1 = &input[index];
-        let in2 = &input[index + 1];
-        let in3 = &input[index + 2];
+    while index < rucksacks.len() {
+        let in1 = &rucksacks[index];
+        let in2 = &rucksacks[index + 1];
+        let in3 = &rucksacks[index + 2];
         let common_item = find_common_item(in1, in2, in3);
         if common_item != ' ' {
             total_priorities += get_priority(common_item);
         }
         index += 3;
     }
-    return total_priorities;
+    return Ok(total_priorities);
 }
-
-fn main() {
-    let lines = read_lines("input.txt");
-    println!("Part 1: {}", part1(&lines));
-    println!("Part 2: {}", part2(&lines));
-}
\ No newline at end of file
@@ -0,0 +1,66 @@
+use crate::scan;
+use crate::util::interval::Interval;
+use crate::util::lenient;
+
+/**
+ * An inclusive range of section IDs.
+ */
+pub type Sections = Interval;
+
+/**
+ * The sections assigned to a pair of Elves.
+ */
+pub type Pair = (Sections, Sections);
+
+/**
+ * Parses lines such as `2-4,6-8`.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Pair>, String> {
+    parse_lines(input.iter().map(|line| line.as_str()))
+}
+
+/**
+ * Same as [`parse`], borrowing the lines instead.
+ */
+pub fn parse_lines<'a>(input: impl IntoIterator<Item = &'a str>) -> Result<Vec<Pair>, String> {
+    lenient::parse_lines(input, |line| {
+        let (a0, a1, b0, b1): (i64, i64, i64, i64) = scan!(line, "{}-{},{}-{}").map_err(|e| e.to_string())?;
+        Ok((Interval::try_from((a0, a1))?, Interval::try_from((b0, b1))?))
+    })
+}
+
+pub fn to_text(pairs: &[Pair]) -> String {
+    pairs.iter().map(|(a, b)| format!("{},{}\n", a, b)).collect()
+}
+
+/**
+ * Counts the number of cases where one range /contains/ the other.
+ *
+ * The ranges are specified as a pair of numbers, and the ranges are inclusive.
+ * Each line in the input contains two ranges, separated by a space ','
+ */
+pub fn part1(pairs: &[Pair]) -> i32 {
+    let mut contain_cnt = 0;
+    for (range1, range2) in pairs {
+        if range1.covers(*range2) || range2.covers(*range1) {
+            contain_cnt += 1;
+        }
+    }
+    return contain_cnt;
+}
+
+/**
+ * Counts the number of cases where one range /overlaps/ the other.
+ *
+ * The ranges are specified as a pair of numbers, and the ranges are inclusive.
+ * Each line in the input contains two ranges, separated by a space ','
+ */
+pub fn part2(pairs: &[Pair]) -> i32 {
+    let mut overlap_cnt = 0;
+    for (range1, range2) in pairs {
+        if range1.overlaps(*range2) {
+            overlap_cnt += 1;
+        }
+    }
+    return overlap_cnt;
+}
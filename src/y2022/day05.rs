@@ -0,0 +1,258 @@
+use serde::{Deserialize, Serialize};
+
+use crate::scan;
+use crate::repl::{self, Query};
+use crate::util::lenient;
+
+/**
+ * Moves `count` crates from stack `from` to stack `to`, numbered from 1 as in
+ * the input.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Move {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+/**
+ * The starting stacks, bottom crate first, and the rearrangement procedure.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Crates {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<Move>,
+}
+
+/**
+ * Get all graphically represented crates from the input file.
+ *
+ * crates are in the format
+ *     [D]
+ * [N] [C]
+ * [Z] [M] [P]
+ *  1   2   3
+ */
+fn get_crates(input: &[String]) -> Result<Vec<Vec<char>>, String> {
+    // get the number of crates and the bottom line number of the crates
+    let mut num_crates = None;
+    let mut bottom_line = 0;
+    for line in input {
+        if line.starts_with(" 1") {
+            let last = line.split_whitespace().last().unwrap();
+            num_crates = Some(last.parse::<usize>().map_err(|_| format!("'{}' is not a stack number", last))?);
+            break;
+        }
+        bottom_line += 1;
+    }
+    let num_crates = num_crates.ok_or("the stack numbers are missing")?;
+
+    // create a vector of crates
+    let mut crates = Vec::new();
+    for _ in 0..num_crates {
+        crates.push(Vec::new());
+    }
+
+    // add the crates to the vector
+    for i in 0..num_crates {
+        for line in input[..bottom_line].iter().rev() {
+            // rows may stop short of the last stacks when those are lower
+            let c = line.chars().nth(1 + (4 * i)).unwrap_or(' ');
+            if c != ' ' {
+                crates[i].push(c);
+            } else {
+                break;
+            }
+        }
+    }
+
+    return Ok(crates);
+}
+
+fn parse_move(line: &str, stacks: usize) -> Result<Move, String> {
+    let (count, from, to): (usize, usize, usize) = scan!(line, "move {} from {} to {}").map_err(|e| e.to_string())?;
+    for stack in [from, to] {
+        if stack == 0 || stack > stacks {
+            return Err(format!("'{}' refers to stack {} of {}", line, stack, stacks));
+        }
+    }
+    Ok(Move { count, from, to })
+}
+
+/**
+ * Parses the drawing of the stacks and the `move N from A to B` lines after it.
+ */
+pub fn parse(input: &[String]) -> Result<Crates, String> {
+    let stacks = get_crates(input)?;
+
+    let mut moves = Vec::new();
+    for (i, line) in input.iter().enumerate() {
+        if line.starts_with("move") {
+            match parse_move(line, stacks.len()) {
+                Ok(m) => moves.push(m),
+                Err(e) => lenient::skip(i, e)?,
+            }
+        }
+    }
+
+    Ok(Crates { stacks, moves })
+}
+
+/**
+ * Draws the stacks and lists the moves as in the puzzle input.
+ */
+pub fn to_text(model: &Crates) -> String {
+    let mut text = String::new();
+    let height = model.stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+    for level in (0..height).rev() {
+        let row: Vec<String> = model
+            .stacks
+            .iter()
+            .map(|stack| match stack.get(level) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect();
+        text.push_str(row.join(" ").trim_end());
+        text.push('\n');
+    }
+    let numbers: Vec<String> = (1..=model.stacks.len()).map(|n| format!(" {} ", n)).collect();
+    text.push_str(&numbers.join(" "));
+    text.push_str("\n\n");
+    for m in &model.moves {
+        text.push_str(&format!("move {} from {} to {}\n", m.count, m.from, m.to));
+    }
+    text
+}
+
+/**
+ * Returns the crates on top of the stacks, skipping the empty ones.
+ */
+fn top_crates(crates: &[Vec<char>]) -> String {
+    let mut result_string = String::new();
+    for c in crates {
+        if let Some(top) = c.last() {
+            result_string.push(*top);
+        }
+    }
+    return result_string;
+}
+
+/**
+ * Performs one move, either one crate at a time or, with `in_batches`, all of
+ * them at once so that they keep their order.
+ *
+ * Fails if the stack holds fewer crates than the move takes.
+ */
+pub fn rearrange(crates: &mut [Vec<char>], m: &Move, in_batches: bool) -> Result<(), String> {
+    let available = crates[m.from - 1].len();
+    if available < m.count {
+        return Err(format!(
+            "'move {} from {} to {}' takes more crates than the {} on stack {}",
+            m.count, m.from, m.to, available, m.from
+        ));
+    }
+    if in_batches {
+        let mut temp = Vec::new();
+        for _ in 0..m.count {
+            temp.push(crates[m.from - 1].pop().unwrap());
+        }
+        while let Some(element) = temp.pop() {
+            crates[m.to - 1].push(element);
+        }
+    } else {
+        for _ in 0..m.count {
+            let item = crates[m.from - 1].pop().unwrap();
+            crates[m.to - 1].push(item);
+        }
+    }
+    Ok(())
+}
+
+/**
+ * Performs the moves according to the instructions and returns and items on the top of the crates.
+ *
+ * Moves are performed one at a time.
+ */
+pub fn part1(model: &Crates) -> Result<String, String> {
+    let mut crates = model.stacks.clone();
+
+    for m in &model.moves {
+        rearrange(&mut crates, m, false)?;
+    }
+
+    return Ok(top_crates(&crates));
+}
+
+/**
+ * Performs the moves according to the instructions and returns and items on the top of the crates.
+ *
+ * Moves are performed in batches.
+ */
+pub fn part2(model: &Crates) -> Result<String, String> {
+    let mut crates = model.stacks.clone();
+
+    for m in &model.moves {
+        rearrange(&mut crates, m, true)?;
+    }
+
+    return Ok(top_crates(&crates));
+}
+
+/**
+ * The stacks after the first `count` moves, one crate at a time as in part 1.
+ */
+fn stacks_after(model: &Crates, count: usize) -> Result<Vec<Vec<char>>, String> {
+    let moves = model
+        .moves
+        .get(..count)
+        .ok_or_else(|| format!("there are only {} moves", model.moves.len()))?;
+    let mut crates = model.stacks.clone();
+    for m in moves {
+        rearrange(&mut crates, m, false)?;
+    }
+    Ok(crates)
+}
+
+pub const QUERIES: &[Query<Crates>] = &[
+    Query {
+        name: "stack",
+        args: "N [MOVES]",
+        help: "the crates on stack N, bottom first, after the first MOVES moves of part 1",
+        run: |model, args| {
+            let number: usize = repl::arg(args, 0, "stack")?;
+            let crates = stacks_after(model, repl::optional_arg(args, 1, "number of moves")?.unwrap_or(0))?;
+            let stack = number
+                .checked_sub(1)
+                .and_then(|index| crates.get(index))
+                .ok_or_else(|| format!("there is no stack {}", number))?;
+            if stack.is_empty() {
+                return Ok("(empty)".to_string());
+            }
+            let crates: Vec<String> = stack.iter().map(|c| format!("[{}]", c)).collect();
+            Ok(crates.join(" "))
+        },
+    },
+    Query {
+        name: "tops",
+        args: "[MOVES]",
+        help: "the crates on top of the stacks after the first MOVES moves of part 1",
+        run: |model, args| {
+            let count = repl::optional_arg(args, 0, "number of moves")?.unwrap_or(model.moves.len());
+            Ok(top_crates(&stacks_after(model, count)?))
+        },
+    },
+    Query {
+        name: "move",
+        args: "N",
+        help: "the Nth move of the procedure",
+        run: |model, args| {
+            let number: usize = repl::arg(args, 0, "move")?;
+            let m = number
+                .checked_sub(1)
+                .and_then(|index| model.moves.get(index))
+                .ok_or_else(|| format!("there is no move {}", number))?;
+            Ok(format!("move {} from {} to {}", m.count, m.from, m.to))
+        },
+    },
+];
@@ -0,0 +1,131 @@
+use crate::cancel;
+
+/**
+ * The characters the communication device receives. The parts take them as
+ * `char`s or, borrowed from the input, as ASCII bytes.
+ */
+pub type Datastream = Vec<char>;
+
+/**
+ * The datastream is the first line of the input.
+ */
+pub fn parse(input: &[String]) -> Result<Datastream, String> {
+    match input.first() {
+        Some(line) => Ok(line.chars().collect()),
+        None => Err("the datastream is missing".to_string()),
+    }
+}
+
+/**
+ * Same as [`parse`], borrowing the datastream as bytes instead. The bytes are
+ * only the characters of an ASCII stream, so any other is refused.
+ */
+pub fn parse_lines<'a>(input: impl IntoIterator<Item = &'a str>) -> Result<&'a [u8], String> {
+    match input.into_iter().next() {
+        Some(line) if line.is_ascii() => Ok(line.as_bytes()),
+        Some(_) => Err("the datastream is not ASCII".to_string()),
+        None => Err("the datastream is missing".to_string()),
+    }
+}
+
+pub fn to_text(stream: &Datastream) -> String {
+    stream.iter().collect::<String>() + "\n"
+}
+
+/**
+ * Check if the 4 characters in a rolling window contains at least two of the same character.
+ * If so, the first character gets processed.
+ *
+ * Returns the number of characters to be processed, or `None` if there is no marker.
+ */
+pub fn part1<T: Copy + Eq>(stream: &[T]) -> Option<i32> {
+    let mut num_chars_to_be_processed = 0;
+
+    for i in 0..stream.len().saturating_sub(3) {
+        cancel::checkpoint();
+        let (c0, c1, c2, c3) = (stream[i], stream[i + 1], stream[i + 2], stream[i + 3]);
+        if c0 == c1 || c0 == c2 || c0 == c3 || c1 == c2 || c1 == c3 || c2 == c3 {
+            num_chars_to_be_processed += 1;
+        } else {
+            return Some(num_chars_to_be_processed + 4);
+        }
+    }
+
+    return None;
+}
+
+/**
+ * Check if the 14 characters in a rolling window contains at least two of the same character.
+ * If so, the first character gets processed.
+ *
+ * Returns the number of characters to be processed, or `None` if there is no marker.
+ */
+pub fn part2<T: Copy + Eq>(stream: &[T]) -> Option<i32> {
+    let mut num_chars_to_be_processed = 0;
+
+    // if 14 consecutive characters contain at least two of the same character,
+    // then the first character gets processed
+    for i in 0..stream.len().saturating_sub(13) {
+        cancel::checkpoint();
+        let mut tmp = Vec::new();
+        for j in 0..14 {
+            let c = stream[i + j];
+            if tmp.contains(&c) {
+                num_chars_to_be_processed += 1;
+                break;
+            } else {
+                tmp.push(c);
+                if tmp.len() == 14 {
+                    return Some(num_chars_to_be_processed + 14);
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+/**
+ * Slides a window of `width` characters over the stream, counting how often
+ * each character is in it, and returns the number of characters processed once
+ * the window holds `width` different ones.
+ */
+fn find_marker<T: Copy + Into<u32>>(stream: &[T], width: usize) -> Option<i32> {
+    // one counter per character code, only letters in practice
+    let size = stream.iter().map(|&c| c.into() as usize + 1).max().unwrap_or(0);
+    let mut counts = vec![0u32; size];
+    let mut distinct = 0;
+    for i in 0..stream.len() {
+        cancel::checkpoint();
+        let entering = stream[i].into() as usize;
+        counts[entering] += 1;
+        if counts[entering] == 1 {
+            distinct += 1;
+        }
+        if i >= width {
+            let leaving = stream[i - width].into() as usize;
+            counts[leaving] -= 1;
+            if counts[leaving] == 0 {
+                distinct -= 1;
+            }
+        }
+        if distinct == width {
+            return Some((i + 1) as i32);
+        }
+    }
+    return None;
+}
+
+/**
+ * Same as [`part1`], with a sliding window instead of comparing every pair.
+ */
+pub fn part1_fast<T: Copy + Into<u32>>(stream: &[T]) -> Option<i32> {
+    find_marker(stream, 4)
+}
+
+/**
+ * Same as [`part2`], with a sliding window instead of rescanning every window.
+ */
+pub fn part2_fast<T: Copy + Into<u32>>(stream: &[T]) -> Option<i32> {
+    find_marker(stream, 14)
+}
@@ -0,0 +1,298 @@
+use std::collections::BTreeMap;
+use std::num::IntErrorKind;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cancel;
+use crate::repl::{self, Query};
+use crate::util::lenient;
+use crate::util::num::Overflow;
+
+/**
+ * A directory with the size of every file in it and its subdirectories, both
+ * keyed by name.
+ */
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Directory {
+    pub files: BTreeMap<String, u64>,
+    pub dirs: BTreeMap<String, Directory>,
+}
+
+impl Directory {
+    /**
+     * The total size of the files in this directory and below it.
+     */
+    pub fn size(&self) -> Result<u64, Overflow> {
+        let mut size: u64 = 0;
+        for file in self.files.values() {
+            size = size.checked_add(*file).ok_or(Overflow("summing a directory size"))?;
+        }
+        for dir in self.dirs.values() {
+            size = size.checked_add(dir.size()?).ok_or(Overflow("summing a directory size"))?;
+        }
+        Ok(size)
+    }
+
+    /**
+     * This directory and every directory below it.
+     */
+    pub fn walk(&self) -> Vec<&Directory> {
+        let mut dirs = vec![self];
+        for dir in self.dirs.values() {
+            dirs.extend(dir.walk());
+        }
+        dirs
+    }
+
+    /**
+     * The sizes of this directory and every directory below it.
+     */
+    pub fn sizes(&self) -> Result<Vec<u64>, Overflow> {
+        let mut sizes = Vec::new();
+        for dir in self.walk() {
+            cancel::checkpoint();
+            sizes.push(dir.size()?);
+        }
+        Ok(sizes)
+    }
+}
+
+/**
+ * Sizes every directory in a single walk, each from the sizes of its
+ * subdirectories, and returns the size of `dir` itself.
+ */
+fn collect_sizes(dir: &Directory, sizes: &mut Vec<u64>) -> Result<u64, Overflow> {
+    cancel::checkpoint();
+    // keep the order of Directory::sizes, a directory before its subdirectories
+    let index = sizes.len();
+    sizes.push(0);
+    let mut size: u64 = 0;
+    for file in dir.files.values() {
+        size = size.checked_add(*file).ok_or(Overflow("summing a directory size"))?;
+    }
+    for subdir in dir.dirs.values() {
+        let subdir_size = collect_sizes(subdir, sizes)?;
+        size = size.checked_add(subdir_size).ok_or(Overflow("summing a directory size"))?;
+    }
+    sizes[index] = size;
+    Ok(size)
+}
+
+/**
+ * The directory tree explored in the terminal session.
+ */
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Filesystem {
+    pub root: Directory,
+}
+
+/**
+ * Replays the terminal session, `cd` moving around the tree and the output
+ * of `ls` filling in the current directory.
+ */
+pub fn parse(input: &[String]) -> Result<Filesystem, String> {
+    let mut root = Directory::default();
+    // the names of the directories from the root down to the current one
+    let mut history: Vec<String> = Vec::new();
+
+    for (i, line) in input.iter().enumerate() {
+        let mut current = &mut root;
+        for name in &history {
+            current = current.dirs.entry(name.clone()).or_default();
+        }
+
+        if let Some(dir) = line.strip_prefix("$ cd ") {
+            match dir {
+                "/" => history.clear(),
+                ".." => {
+                    history.pop();
+                }
+                _ => history.push(dir.to_string()),
+            }
+        } else if line.starts_with("$") || line.is_empty() {
+            // `ls` lists the current directory on the lines after it
+        } else if let Some(dir) = line.strip_prefix("dir ") {
+            current.dirs.entry(dir.to_string()).or_default();
+        } else {
+            let Some((size_text, file_name)) = line.split_once(' ') else {
+                lenient::skip(i, format!("'{}' is not a file", line))?;
+                continue;
+            };
+            let file_size = match size_text.parse::<u64>() {
+                Ok(size) => size,
+                Err(e) if *e.kind() == IntErrorKind::PosOverflow => {
+                    return Err(Overflow("parsing a file size").to_string());
+                }
+                Err(e) => {
+                    lenient::skip(i, format!("invalid file size '{}': {}", size_text, e))?;
+                    continue;
+                }
+            };
+            current.files.insert(file_name.to_string(), file_size);
+        }
+    }
+
+    // every later question involves the total size
+    root.size().map_err(|e| e.to_string())?;
+    Ok(Filesystem { root })
+}
+
+/**
+ * Writes a terminal session that explores the whole tree, listing every
+ * directory before descending into its subdirectories.
+ */
+pub fn to_text(filesystem: &Filesystem) -> String {
+    fn explore(dir: &Directory, lines: &mut Vec<String>) {
+        lines.push("$ ls".to_string());
+        for name in dir.dirs.keys() {
+            lines.push(format!("dir {}", name));
+        }
+        for (name, size) in &dir.files {
+            lines.push(format!("{} {}", size, name));
+        }
+        for (name, subdir) in &dir.dirs {
+            lines.push(format!("$ cd {}", name));
+            explore(subdir, lines);
+            lines.push("$ cd ..".to_string());
+        }
+    }
+
+    let mut lines = vec!["$ cd /".to_string()];
+    explore(&filesystem.root, &mut lines);
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+/**
+ * Returns the sum of all directories with size <= 100000.
+ */
+pub fn part1(filesystem: &Filesystem) -> Result<u64, Overflow> {
+    // sums all directories with size <= 100000
+    let mut sum: u64 = 0;
+    for size in filesystem.root.sizes()? {
+        if size <= 100000 {
+            sum = sum.checked_add(size).ok_or(Overflow("summing the small directories"))?;
+        }
+    }
+
+    return Ok(sum);
+}
+
+/**
+ * Same as [`part1`], sizing the directories bottom up in one walk.
+ */
+pub fn part1_fast(filesystem: &Filesystem) -> Result<u64, Overflow> {
+    let mut sizes = Vec::new();
+    collect_sizes(&filesystem.root, &mut sizes)?;
+
+    let mut sum: u64 = 0;
+    for size in sizes.into_iter().filter(|&size| size <= 100000) {
+        sum = sum.checked_add(size).ok_or(Overflow("summing the small directories"))?;
+    }
+    Ok(sum)
+}
+
+/**
+ * Returns the size of the smallest directory which, when freed,
+ * would allow the total space to be >= 30000000.
+ */
+pub fn part2(filesystem: &Filesystem) -> Result<u64, String> {
+    let sizes = filesystem.root.sizes().map_err(|e| e.to_string())?;
+    smallest_to_free(&sizes)
+}
+
+/**
+ * Same as [`part2`], sizing the directories bottom up in one walk.
+ */
+pub fn part2_fast(filesystem: &Filesystem) -> Result<u64, String> {
+    let mut sizes = Vec::new();
+    collect_sizes(&filesystem.root, &mut sizes).map_err(|e| e.to_string())?;
+    smallest_to_free(&sizes)
+}
+
+/**
+ * Picks the smallest directory to delete given the sizes of all of them, the
+ * root directory first.
+ */
+fn smallest_to_free(sizes: &[u64]) -> Result<u64, String> {
+    let used = sizes[0];
+
+    let total_space: u64 = 70000000;
+    let goal: u64 = 30000000;
+    let free = total_space
+        .checked_sub(used)
+        .ok_or_else(|| format!("{} bytes are used on a disk of {}", used, total_space))?;
+    let want = goal.saturating_sub(free);
+
+    // find the smallest directory that is >= want
+    let mut smallest = u64::MAX;
+    for &size in sizes {
+        if (size >= want) && (size < smallest) {
+            smallest = size;
+        }
+    }
+
+    return Ok(smallest);
+}
+
+/**
+ * Looks up a directory by its absolute path such as `/a/e`.
+ */
+fn find<'a>(root: &'a Directory, path: &str) -> Result<&'a Directory, String> {
+    let mut dir = root;
+    for name in path.split('/').filter(|name| !name.is_empty()) {
+        dir = dir.dirs.get(name).ok_or_else(|| format!("there is no directory '{}'", path))?;
+    }
+    Ok(dir)
+}
+
+/**
+ * Every directory below `dir` with its path, `dir` itself first.
+ */
+fn walk_paths<'a>(dir: &'a Directory, path: String, dirs: &mut Vec<(String, &'a Directory)>) {
+    dirs.push((path.clone(), dir));
+    for (name, subdir) in &dir.dirs {
+        walk_paths(subdir, format!("{}{}/", path, name), dirs);
+    }
+}
+
+pub const QUERIES: &[Query<Filesystem>] = &[
+    Query {
+        name: "larger",
+        args: "SIZE",
+        help: "the directories with a total size above SIZE",
+        run: |filesystem, args| {
+            let limit: u64 = repl::arg(args, 0, "size")?;
+            let mut dirs = Vec::new();
+            walk_paths(&filesystem.root, "/".to_string(), &mut dirs);
+            let mut lines = Vec::new();
+            for (path, dir) in dirs {
+                let size = dir.size().map_err(|e| e.to_string())?;
+                if size > limit {
+                    lines.push(format!("{:>10} {}", size, path));
+                }
+            }
+            lines.push(format!("{} directories", lines.len()));
+            Ok(lines.join("\n"))
+        },
+    },
+    Query {
+        name: "size",
+        args: "[PATH]",
+        help: "the total size of a directory, the root by default",
+        run: |filesystem, args| {
+            let dir = find(&filesystem.root, args.first().unwrap_or(&"/"))?;
+            dir.size().map(|size| size.to_string()).map_err(|e| e.to_string())
+        },
+    },
+    Query {
+        name: "ls",
+        args: "[PATH]",
+        help: "the subdirectories and files of a directory, as `ls` lists them",
+        run: |filesystem, args| {
+            let dir = find(&filesystem.root, args.first().unwrap_or(&"/"))?;
+            let mut lines: Vec<String> = dir.dirs.keys().map(|name| format!("dir {}", name)).collect();
+            lines.extend(dir.files.iter().map(|(name, size)| format!("{} {}", size, name)));
+            Ok(lines.join("\n"))
+        },
+    },
+];
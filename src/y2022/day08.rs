@@ -0,0 +1,138 @@
+use crate::util::grid::{Grid, Pos, ORTHOGONAL};
+
+/**
+ * The height of every tree in the grid.
+ */
+pub type Forest = Grid<i32>;
+
+/**
+ * Parses the tree heights, one digit per tree.
+ */
+pub fn parse(input: &[String]) -> Result<Forest, String> {
+    Grid::parse(input, |c| {
+        c.to_digit(10)
+            .map(|d| d as i32)
+            .ok_or_else(|| format!("'{}' is not a tree height", c))
+    })
+}
+
+pub fn to_text(tree_map: &Forest) -> String {
+    let mut text = String::new();
+    for row in tree_map.rows() {
+        for height in row {
+            text.push_str(&height.to_string());
+        }
+        text.push('\n');
+    }
+    text
+}
+
+/**
+ * Counts the trees that are visible from outside the grid.
+ *
+ * A tree is hidden only if a tree at least as tall blocks it in all four directions.
+ */
+pub fn part1(tree_map: &Forest) -> i32 {
+    // a tree is visible if every tree towards some edge is lower,
+    // trees on the edge have nothing in the way at all
+    let is_visible = |pos: Pos| {
+        ORTHOGONAL
+            .iter()
+            .any(|&d| tree_map.ray(pos, d).all(|p| tree_map[p] < tree_map[pos]))
+    };
+
+    let visible_num = tree_map.positions().filter(|&pos| is_visible(pos)).count();
+
+    return visible_num as i32;
+}
+
+/**
+ * Returns the highest scenic score, the product of the viewing distances in all four directions,
+ * or `None` if there are no trees.
+ */
+pub fn part2(tree_map: &Forest) -> Option<i32> {
+    let mut max_visibility = None;
+
+    for pos in tree_map.positions() {
+        // the viewing distance in each direction counts the trees up to and
+        // including the first one that is at least as tall
+        let mut visibility = 1;
+        for &direction in ORTHOGONAL.iter() {
+            let mut distance = 0;
+            for p in tree_map.ray(pos, direction) {
+                distance += 1;
+                if tree_map[p] >= tree_map[pos] {
+                    break;
+                }
+            }
+            visibility *= distance;
+        }
+
+        if max_visibility.is_none_or(|max| visibility > max) {
+            max_visibility = Some(visibility);
+        }
+    }
+
+    return max_visibility;
+}
+
+/**
+ * Walks every row and column from both ends with a stack of the trees seen so
+ * far, lowest on top. Before a tree is pushed, the lower trees it hides are
+ * popped: the tree is visible from that end if nothing is left, and otherwise
+ * the tree left on top is where its view towards that end stops.
+ *
+ * Returns the visibility and the scenic score of every tree.
+ */
+fn scan_sight_lines(tree_map: &Forest) -> (Grid<bool>, Grid<i32>) {
+    let (width, height) = (tree_map.width(), tree_map.height());
+    let mut visible = Grid::new(width, height, false);
+    let mut score = Grid::new(width, height, 1);
+
+    let mut lines: Vec<Vec<Pos>> = Vec::new();
+    for row in 0..height {
+        let line: Vec<Pos> = (0..width).map(|col| (row, col)).collect();
+        lines.push(line.iter().rev().copied().collect());
+        lines.push(line);
+    }
+    for col in 0..width {
+        let line: Vec<Pos> = (0..height).map(|row| (row, col)).collect();
+        lines.push(line.iter().rev().copied().collect());
+        lines.push(line);
+    }
+
+    for line in lines {
+        let mut stack: Vec<usize> = Vec::new();
+        for (i, &pos) in line.iter().enumerate() {
+            while stack.last().is_some_and(|&j| tree_map[line[j]] < tree_map[pos]) {
+                stack.pop();
+            }
+            match stack.last() {
+                Some(&j) => score[pos] *= (i - j) as i32,
+                None => {
+                    visible[pos] = true;
+                    score[pos] *= i as i32;
+                }
+            }
+            stack.push(i);
+        }
+    }
+
+    (visible, score)
+}
+
+/**
+ * Same as [`part1`], looking along each row and column once.
+ */
+pub fn part1_fast(tree_map: &Forest) -> i32 {
+    let (visible, _) = scan_sight_lines(tree_map);
+    visible.iter().filter(|(_, &v)| v).count() as i32
+}
+
+/**
+ * Same as [`part2`], looking along each row and column once.
+ */
+pub fn part2_fast(tree_map: &Forest) -> Option<i32> {
+    let (_, score) = scan_sight_lines(tree_map);
+    score.iter().map(|(_, &s)| s).max()
+}
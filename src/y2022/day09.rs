@@ -0,0 +1,239 @@
+use std::collections::HashSet;
+use std::convert::Infallible;
+
+use serde::{Deserialize, Serialize};
+
+use crate::sim::Simulation;
+use crate::util::lenient;
+use crate::util::point::{Direction, Point};
+
+/**
+ * Given the location of two knots, return if they are touching each other.
+ *
+ * Two knots are touching if they are on the same row, column, or diagonal.
+ */
+fn is_touching(a: Point, b: Point) -> bool {
+    a.chebyshev(b) <= 1
+}
+
+/**
+ * Given the locations of the previous and the current knot,
+ * return the updated location of the current knot.
+ *
+ * The current knot moves one step towards the previous one, diagonally if
+ * they are neither on the same row nor on the same column.
+ */
+fn do_move(prev: Point, curr: Point) -> Point {
+    curr + (prev - curr).signum()
+}
+
+/**
+ * The head moves `steps` times in `direction`.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Motion {
+    pub direction: Direction,
+    pub steps: i32,
+}
+
+/**
+ * The knots of a rope, head first.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rope {
+    pub knots: Vec<Point>,
+}
+
+impl Rope {
+    /**
+     * A rope of `length` knots, all at the origin.
+     */
+    pub fn new(length: usize) -> Rope {
+        Rope {
+            knots: vec![Point::ORIGIN; length],
+        }
+    }
+
+    pub fn tail(&self) -> Point {
+        *self.knots.last().unwrap()
+    }
+
+    /**
+     * Moves the head one step, each other knot following the one before it.
+     */
+    pub fn step(&mut self, direction: Direction) {
+        let knots = &mut self.knots;
+        knots[0] += direction.vector();
+        for k in 0..knots.len() - 1 {
+            if !is_touching(knots[k], knots[k + 1]) {
+                knots[k + 1] = do_move(knots[k], knots[k + 1]);
+            }
+        }
+    }
+}
+
+/**
+ * Parses lines in the format of "direction distance".
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Motion>, String> {
+    lenient::parse_lines(input, |line| {
+        let args: Vec<&str> = line.split_whitespace().collect();
+        let (direction, steps) = match args[..] {
+            [direction, steps] => (direction, steps),
+            _ => return Err(format!("'{}' is not a motion", line)),
+        };
+        let direction = match direction.chars().collect::<Vec<char>>()[..] {
+            [c] => Direction::from_char(c),
+            _ => None,
+        }
+        .ok_or_else(|| format!("'{}' is not a direction", direction))?;
+        let steps = steps.parse::<i32>().map_err(|e| format!("'{}' is not a distance: {}", steps, e))?;
+        Ok(Motion { direction, steps })
+    })
+}
+
+pub fn to_text(motions: &[Motion]) -> String {
+    motions.iter().map(|m| format!("{} {}\n", m.direction.to_char(), m.steps)).collect()
+}
+
+/**
+ * How far the rope has been pulled through the motions.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RopeState {
+    pub rope: Rope,
+    /// The motion being made and how many of its steps are done.
+    pub motion: usize,
+    pub done: i32,
+    /// Every location the tail knot has visited.
+    pub visited: HashSet<Point>,
+}
+
+/**
+ * Pulls a rope through the motions, one step of the head at a time.
+ */
+pub struct RopeSimulation {
+    motions: Vec<Motion>,
+    state: RopeState,
+}
+
+impl RopeSimulation {
+    pub fn new(motions: &[Motion], length: usize) -> RopeSimulation {
+        let rope = Rope::new(length);
+        RopeSimulation {
+            motions: motions.to_vec(),
+            state: RopeState {
+                visited: HashSet::from([rope.tail()]),
+                rope,
+                motion: 0,
+                done: 0,
+            },
+        }
+    }
+}
+
+impl Simulation for RopeSimulation {
+    type State = RopeState;
+    type Error = Infallible;
+
+    fn step(&mut self) -> Result<bool, Infallible> {
+        let state = &mut self.state;
+
+        // move on to the next motion with steps left
+        while state.motion < self.motions.len() && state.done >= self.motions[state.motion].steps {
+            state.motion += 1;
+            state.done = 0;
+        }
+        let Some(motion) = self.motions.get(state.motion) else {
+            return Ok(false);
+        };
+
+        state.rope.step(motion.direction);
+        state.done += 1;
+
+        // add the tail knot to the visited set
+        state.visited.insert(state.rope.tail());
+        Ok(true)
+    }
+
+    fn state(&self) -> &RopeState {
+        &self.state
+    }
+
+    fn restore(&mut self, state: RopeState) {
+        self.state = state;
+    }
+
+    /**
+     * Draws the knots over the tail's trail as in the puzzle description: `H`
+     * for the head, `T` or the knot's number for the others, `#` where the
+     * tail has been and `s` for the start.
+     */
+    fn render(&self) -> String {
+        let RopeState { rope, visited, .. } = &self.state;
+        let points: Vec<Point> = rope.knots.iter().chain(visited).copied().chain([Point::ORIGIN]).collect();
+        let min_x = points.iter().map(|p| p.x).min().unwrap();
+        let max_x = points.iter().map(|p| p.x).max().unwrap();
+        let min_y = points.iter().map(|p| p.y).min().unwrap();
+        let max_y = points.iter().map(|p| p.y).max().unwrap();
+
+        let mut rows = Vec::new();
+        for y in min_y..=max_y {
+            let row: String = (min_x..=max_x)
+                .map(|x| {
+                    let p = Point::new(x, y);
+                    match rope.knots.iter().position(|&knot| knot == p) {
+                        Some(0) => 'H',
+                        Some(_) if rope.knots.len() == 2 => 'T',
+                        Some(k) => char::from_digit(k as u32 % 10, 10).unwrap(),
+                        None if p == Point::ORIGIN => 's',
+                        None if visited.contains(&p) => '#',
+                        None => '.',
+                    }
+                })
+                .collect();
+            rows.push(row);
+        }
+        rows.join("\n")
+    }
+}
+
+/**
+ * Pulls a rope of `length` knots through the motions and returns every
+ * location its tail knot has visited.
+ */
+pub fn tail_trail(motions: &[Motion], length: usize) -> HashSet<Point> {
+    let mut simulation = RopeSimulation::new(motions, length);
+    let Ok(()) = simulation.run();
+    return simulation.state.visited;
+}
+
+/**
+ * The simulation of part 1, a rope with two knots.
+ */
+pub fn part1_simulation(motions: &[Motion]) -> RopeSimulation {
+    RopeSimulation::new(motions, 2)
+}
+
+/**
+ * The simulation of part 2, a rope with ten knots.
+ */
+pub fn part2_simulation(motions: &[Motion]) -> RopeSimulation {
+    RopeSimulation::new(motions, 10)
+}
+
+/**
+ * Returns the number of unique locations that the tail of a rope with two
+ * knots has visited.
+ */
+pub fn part1(motions: &[Motion]) -> i32 {
+    return tail_trail(motions, 2).len() as i32;
+}
+
+/**
+ * Returns the number of unique locations that the tail of a rope with ten
+ * knots has visited.
+ */
+pub fn part2(motions: &[Motion]) -> i32 {
+    return tail_trail(motions, 10).len() as i32;
+}
@@ -0,0 +1,198 @@
+use std::convert::Infallible;
+
+use serde::{Deserialize, Serialize};
+
+use crate::scan;
+use crate::sim::Simulation;
+use crate::util::lenient;
+
+/**
+ * One instruction of the CPU's program.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Instruction {
+    /// Takes one cycle and does nothing.
+    Noop,
+    /// Takes two cycles, then adds its operand to register X.
+    Addx(i32),
+}
+
+/**
+ * Parses one `noop` or `addx V` instruction per line.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Instruction>, String> {
+    parse_lines(input.iter().map(|line| line.as_str()))
+}
+
+/**
+ * Same as [`parse`], borrowing the lines instead.
+ */
+pub fn parse_lines<'a>(input: impl IntoIterator<Item = &'a str>) -> Result<Vec<Instruction>, String> {
+    lenient::parse_lines(input, |line| {
+        if line == "noop" {
+            Ok(Instruction::Noop)
+        } else {
+            let operand: i32 = scan!(line, "addx {}").map_err(|e| e.to_string())?;
+            Ok(Instruction::Addx(operand))
+        }
+    })
+}
+
+pub fn to_text(program: &[Instruction]) -> String {
+    program
+        .iter()
+        .map(|instruction| match instruction {
+            Instruction::Noop => "noop\n".to_string(),
+            Instruction::Addx(operand) => format!("addx {}\n", operand),
+        })
+        .collect()
+}
+
+/**
+ * Produce a pixel for the print buffer based on the cycle and the register value.
+ *
+ * reg is a 3-pixel wide sprite
+ * if the sprite overlaps with the current cycle, return a '#', otherwise return a '.'
+ */
+fn produce_pixel(cycle: i32, reg: i32) -> char {
+    if ((cycle % 40) - reg).abs() <= 1 {
+        return '#';
+    } else {
+        return '.';
+    }
+}
+
+/**
+ * The CPU and the CRT between two cycles.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CpuState {
+    /// The number of cycles completed.
+    pub cycle: i32,
+    /// Register X.
+    pub x: i32,
+    /// The index of the instruction being executed.
+    pub pc: usize,
+    /// Whether the `addx` being executed has already spent its first cycle.
+    pub busy: bool,
+    /// The sum of the signal strengths during cycles 20, 60, 100 and so on.
+    pub signal_strength: i32,
+    /// Every pixel the CRT has drawn.
+    pub pixels: String,
+}
+
+/**
+ * Executes the program one cycle at a time, the CRT drawing a pixel during
+ * each cycle.
+ */
+pub struct Cpu {
+    program: Vec<Instruction>,
+    state: CpuState,
+}
+
+impl Cpu {
+    pub fn new(program: &[Instruction]) -> Cpu {
+        Cpu {
+            program: program.to_vec(),
+            state: CpuState {
+                cycle: 0,
+                x: 1,
+                pc: 0,
+                busy: false,
+                signal_strength: 0,
+                pixels: String::new(),
+            },
+        }
+    }
+}
+
+impl Simulation for Cpu {
+    type State = CpuState;
+    type Error = Infallible;
+
+    fn step(&mut self) -> Result<bool, Infallible> {
+        let state = &mut self.state;
+        let Some(&instruction) = self.program.get(state.pc) else {
+            return Ok(false);
+        };
+
+        // the strength of the signal is the product of the cycle and the register value
+        let during = state.cycle + 1;
+        if (during - 20) % 40 == 0 {
+            state.signal_strength += during * state.x;
+        }
+        state.pixels.push(produce_pixel(state.cycle, state.x));
+        state.cycle += 1;
+
+        // addx only finishes, and changes the register, after its second cycle
+        match instruction {
+            Instruction::Noop => state.pc += 1,
+            Instruction::Addx(_) if !state.busy => state.busy = true,
+            Instruction::Addx(operand) => {
+                state.x += operand;
+                state.busy = false;
+                state.pc += 1;
+            }
+        }
+        Ok(true)
+    }
+
+    fn state(&self) -> &CpuState {
+        &self.state
+    }
+
+    fn restore(&mut self, state: CpuState) {
+        self.state = state;
+    }
+
+    fn render(&self) -> String {
+        let state = &self.state;
+        let mut text = format!(
+            "cycle {}, X = {}, signal strength {}",
+            state.cycle, state.x, state.signal_strength
+        );
+        if !state.pixels.is_empty() {
+            text.push('\n');
+            text.push_str(&crt_lines(&state.pixels));
+        }
+        text
+    }
+}
+
+/**
+ * Splits the pixels into the lines of the CRT, 40 pixels each.
+ */
+fn crt_lines(pixels: &str) -> String {
+    let pixels: Vec<char> = pixels.chars().collect();
+    let lines: Vec<String> = pixels.chunks(40).map(|line| line.iter().collect()).collect();
+    lines.join("\n")
+}
+
+/**
+ * The CPU simulation, the same for both parts.
+ */
+pub fn simulation(program: &[Instruction]) -> Cpu {
+    Cpu::new(program)
+}
+
+/**
+ * Calculates the sum of the strength of the signal at each cycle
+ *
+ * the strength of the signal is the product of the cycle and the register value
+ */
+pub fn part1(program: &[Instruction]) -> i32 {
+    let mut cpu = Cpu::new(program);
+    let Ok(()) = cpu.run();
+    return cpu.state.signal_strength;
+}
+
+/**
+ * Executes the program, filling the print buffer at each cycle, and returns
+ * what the CRT shows, 40 pixels per line.
+ */
+pub fn part2(program: &[Instruction]) -> String {
+    let mut cpu = Cpu::new(program);
+    let Ok(()) = cpu.run();
+    return crt_lines(&cpu.state.pixels);
+}
@@ -0,0 +1,412 @@
+use serde::{Deserialize, Serialize};
+
+use crate::cancel;
+use crate::scan;
+use crate::repl::{self, Query};
+use crate::sim::Simulation;
+use crate::util::lenient;
+use crate::util::num::Overflow;
+
+/**
+ * Either side of a monkey's operation.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Operand {
+    /// The worry level before the operation.
+    Old,
+    Value(u64),
+}
+
+impl Operand {
+    fn value(self, old: u64) -> u64 {
+        match self {
+            Operand::Old => old,
+            Operand::Value(value) => value,
+        }
+    }
+}
+
+impl std::fmt::Display for Operand {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Operand::Old => write!(f, "old"),
+            Operand::Value(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+/**
+ * A monkey's starting items, how it changes the worry level, its test divisor
+ * and the monkeys it throws to when the test passes or fails.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub operator: Operator,
+    pub operands: (Operand, Operand),
+    pub divisor: u64,
+    pub if_true: usize,
+    pub if_false: usize,
+}
+
+fn parse_operand(s: &str) -> Result<Operand, String> {
+    if s == "old" {
+        return Ok(Operand::Old);
+    }
+    s.parse::<u64>()
+        .map(Operand::Value)
+        .map_err(|e| format!("'{}' is not an operand: {}", s, e))
+}
+
+/**
+ * Parses the notes on every monkey.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Monkey>, String> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut i = 0;
+    while i < input.len() {
+        if input[i].starts_with("Monkey") {
+            let monkey_line = i;
+            let mut next_line = || {
+                i += 1;
+                input
+                    .get(i)
+                    .map(|line| line.trim())
+                    .ok_or_else(|| format!("the notes on the monkey at line {} are cut short", monkey_line + 1))
+            };
+
+            // parse items, a monkey may start without any
+            let line = next_line()?;
+            let items_list = line
+                .strip_prefix("Starting items:")
+                .ok_or_else(|| format!("'{}' does not list the starting items", line))?
+                .trim();
+            let mut items = Vec::new();
+            for item in items_list.split(", ").filter(|item| !item.is_empty()) {
+                match item.parse::<u64>() {
+                    Ok(item) => items.push(item),
+                    // the monkey keeps its other items
+                    Err(e) => lenient::repair(monkey_line + 1, format!("'{}' is not a worry level: {}", item, e))?,
+                }
+            }
+
+            // parse the operation and its operands
+            let (operand1, operation, operand2): (&str, &str, &str) =
+                scan!(next_line()?, "Operation: new = {} {} {}").map_err(|e| e.to_string())?;
+            let operator = match operation {
+                "*" => Operator::Multiply,
+                "+" => Operator::Add,
+                _ => return Err(format!("unknown operation '{}'", operation)),
+            };
+            let operands = (parse_operand(operand1)?, parse_operand(operand2)?);
+
+            // parse divisible by
+            let divisor: u64 = scan!(next_line()?, "Test: divisible by {}").map_err(|e| e.to_string())?;
+            if divisor == 0 {
+                return Err("a monkey tests for divisibility by 0".to_string());
+            }
+
+            // parse the destinations
+            let if_true: usize = scan!(next_line()?, "If true: throw to monkey {}").map_err(|e| e.to_string())?;
+            let if_false: usize = scan!(next_line()?, "If false: throw to monkey {}").map_err(|e| e.to_string())?;
+
+            monkeys.push(Monkey { items, operator, operands, divisor, if_true, if_false });
+        }
+        i += 1;
+    }
+
+    for (i, monkey) in monkeys.iter().enumerate() {
+        for destination in [monkey.if_true, monkey.if_false] {
+            if destination >= monkeys.len() {
+                return Err(format!("there is no monkey {} to throw to", destination));
+            }
+            // the monkey would keep inspecting the item forever
+            if destination == i {
+                return Err(format!("monkey {} throws to itself", i));
+            }
+        }
+    }
+
+    return Ok(monkeys);
+}
+
+/**
+ * Writes the notes on every monkey back in the puzzle's words.
+ */
+pub fn to_text(monkeys: &[Monkey]) -> String {
+    let mut notes = Vec::new();
+    for (i, monkey) in monkeys.iter().enumerate() {
+        let items: Vec<String> = monkey.items.iter().map(|item| item.to_string()).collect();
+        let operation = match monkey.operator {
+            Operator::Add => '+',
+            Operator::Multiply => '*',
+        };
+        notes.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {} {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i,
+            items.join(", "),
+            monkey.operands.0,
+            operation,
+            monkey.operands.1,
+            monkey.divisor,
+            monkey.if_true,
+            monkey.if_false
+        ));
+    }
+    notes.join("\n")
+}
+
+/**
+ * The product of the two highest inspection counts, `None` with fewer than two
+ * monkeys.
+ */
+fn monkey_business(inspects: &[u64]) -> Result<Option<u64>, Overflow> {
+    if inspects.len() < 2 {
+        return Ok(None);
+    }
+
+    // find the two largest inspect counts
+    let mut max1 = 0;
+    let mut max2 = 0;
+    for i in 0..inspects.len() {
+        if inspects[i] > max1 {
+            max2 = max1;
+            max1 = inspects[i];
+        } else if inspects[i] > max2 {
+            max2 = inspects[i];
+        }
+    }
+
+    max1.checked_mul(max2).map(Some).ok_or(Overflow("multiplying the inspection counts"))
+}
+
+/**
+ * How the worry level is kept manageable after an inspection.
+ */
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Relief {
+    /// Part 1: the worry level is divided by three.
+    DivideByThree,
+    /// Part 2: the worry level is kept modulo the product of the monkeys'
+    /// divisors, or overflowed computing it.
+    Modulo(Result<u64, Overflow>),
+}
+
+/**
+ * Who holds which items after some rounds, and how often each monkey has
+ * inspected an item.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeepAwayState {
+    pub round: usize,
+    pub items: Vec<Vec<u64>>,
+    pub inspects: Vec<u64>,
+}
+
+/**
+ * The monkeys playing keep away with the items, one round per step.
+ */
+pub struct KeepAway {
+    monkeys: Vec<Monkey>,
+    rounds: usize,
+    relief: Relief,
+    state: KeepAwayState,
+}
+
+impl KeepAway {
+    fn new(monkeys: &[Monkey], rounds: usize, relief: Relief) -> KeepAway {
+        KeepAway {
+            monkeys: monkeys.to_vec(),
+            rounds,
+            relief,
+            state: KeepAwayState {
+                round: 0,
+                items: monkeys.iter().map(|monkey| monkey.items.clone()).collect(),
+                inspects: vec![0; monkeys.len()],
+            },
+        }
+    }
+
+    /**
+     * The new worry level of an item once `monkey` has inspected it.
+     */
+    fn inspect(&self, monkey: &Monkey, item: u64) -> Result<u64, Overflow> {
+        match &self.relief {
+            Relief::DivideByThree => {
+                let (op1, op2) = (monkey.operands.0.value(item), monkey.operands.1.value(item));
+                let worry_level = match monkey.operator {
+                    Operator::Multiply => op1.checked_mul(op2),
+                    Operator::Add => op1.checked_add(op2),
+                }
+                .ok_or(Overflow("computing a worry level"))?;
+                Ok(worry_level / 3)
+            }
+            Relief::Modulo(Err(overflow)) => Err(overflow.clone()),
+            Relief::Modulo(Ok(modulus)) => {
+                // both operands are below 2^64, so neither result can overflow a u128
+                let (op1, op2) = (monkey.operands.0.value(item) as u128, monkey.operands.1.value(item) as u128);
+                let worry_level = match monkey.operator {
+                    Operator::Multiply => op1 * op2,
+                    Operator::Add => op1 + op2,
+                };
+                Ok((worry_level % *modulus as u128) as u64)
+            }
+        }
+    }
+}
+
+impl Simulation for KeepAway {
+    type State = KeepAwayState;
+    type Error = Overflow;
+
+    /**
+     * Plays one round: each monkey in turn inspects and throws all the items it holds.
+     */
+    fn step(&mut self) -> Result<bool, Overflow> {
+        if self.state.round >= self.rounds {
+            return Ok(false);
+        }
+
+        for i in 0..self.monkeys.len() {
+            let monkey = &self.monkeys[i];
+
+            // a single turn can go through a long list of items, so keep it cancellable
+            while !self.state.items[i].is_empty() {
+                cancel::checkpoint();
+                self.state.inspects[i] += 1;
+
+                let item = self.state.items[i].remove(0);
+                let worry_level = self.inspect(monkey, item)?;
+
+                if worry_level.is_multiple_of(monkey.divisor) {
+                    self.state.items[monkey.if_true].push(worry_level);
+                } else {
+                    self.state.items[monkey.if_false].push(worry_level);
+                }
+            }
+        }
+
+        self.state.round += 1;
+        Ok(true)
+    }
+
+    fn state(&self) -> &KeepAwayState {
+        &self.state
+    }
+
+    fn restore(&mut self, state: KeepAwayState) {
+        self.state = state;
+    }
+
+    /**
+     * Lists the items each monkey holds and its inspections, as in the puzzle
+     * description.
+     */
+    fn render(&self) -> String {
+        let state = &self.state;
+        let mut lines = vec![format!("After round {}:", state.round)];
+        for (i, items) in state.items.iter().enumerate() {
+            let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+            lines.push(format!("Monkey {}: {}", i, items.join(", ")).trim_end().to_string());
+        }
+        for (i, inspects) in state.inspects.iter().enumerate() {
+            lines.push(format!("Monkey {} inspected items {} times.", i, inspects));
+        }
+        lines.join("\n")
+    }
+}
+
+/**
+ * The simulation of part 1, 20 rounds after which the worry level is divided by three.
+ */
+pub fn part1_simulation(monkeys: &[Monkey]) -> KeepAway {
+    KeepAway::new(monkeys, 20, Relief::DivideByThree)
+}
+
+/**
+ * The simulation of part 2, 10000 rounds without relief.
+ *
+ * To make sure the worry levels are not too large, they are kept modulo the product of the monkeys' divisors.
+ */
+pub fn part2_simulation(monkeys: &[Monkey]) -> KeepAway {
+    // every test only cares about the worry level modulo its divisor,
+    // so keeping it modulo the product of all divisors changes no decision
+    let modulus = monkeys
+        .iter()
+        .try_fold(1u64, |product, monkey| product.checked_mul(monkey.divisor))
+        .ok_or(Overflow("multiplying the test divisors"));
+    KeepAway::new(monkeys, 10000, Relief::Modulo(modulus))
+}
+
+/**
+ * Run the simulation for part 1.
+ *
+ * Each monkey does its work, and the items are passed to the next monkey.
+ * The simulation runs for 20 rounds.
+ * The product of the two highest inspection counts is returned.
+ */
+pub fn part1(monkeys: &[Monkey]) -> Result<Option<u64>, Overflow> {
+    let mut simulation = part1_simulation(monkeys);
+    simulation.run()?;
+    return monkey_business(&simulation.state.inspects);
+}
+
+/**
+ * Run the simulation for part 2.
+ *
+ * Each monkey does its work, and the items are passed to the next monkey.
+ * The simulation runs for 10000 rounds.
+ * The product of the two highest inspection counts is returned.
+ */
+pub fn part2(monkeys: &[Monkey]) -> Result<Option<u64>, Overflow> {
+    let mut simulation = part2_simulation(monkeys);
+    simulation.run()?;
+    return monkey_business(&simulation.state.inspects);
+}
+
+/**
+ * Who holds what after the given number of rounds of part 1.
+ */
+fn after_rounds(monkeys: &[Monkey], rounds: usize) -> Result<KeepAwayState, String> {
+    let mut simulation = KeepAway::new(monkeys, rounds, Relief::DivideByThree);
+    simulation.run().map_err(|e| e.to_string())?;
+    Ok(simulation.state)
+}
+
+pub const QUERIES: &[Query<Vec<Monkey>>] = &[
+    Query {
+        name: "items",
+        args: "MONKEY [ROUNDS]",
+        help: "the worry levels of the items a monkey holds after some rounds of part 1",
+        run: |monkeys, args| {
+            let monkey: usize = repl::arg(args, 0, "monkey")?;
+            let state = after_rounds(monkeys, repl::optional_arg(args, 1, "number of rounds")?.unwrap_or(0))?;
+            let items = state.items.get(monkey).ok_or_else(|| format!("there is no monkey {}", monkey))?;
+            let items: Vec<String> = items.iter().map(|item| item.to_string()).collect();
+            Ok(format!("Monkey {}: {}", monkey, items.join(", ")).trim_end().to_string())
+        },
+    },
+    Query {
+        name: "inspects",
+        args: "[ROUNDS]",
+        help: "how often each monkey has inspected an item after some rounds of part 1, 20 by default",
+        run: |monkeys, args| {
+            let state = after_rounds(monkeys, repl::optional_arg(args, 0, "number of rounds")?.unwrap_or(20))?;
+            let lines: Vec<String> = state
+                .inspects
+                .iter()
+                .enumerate()
+                .map(|(i, inspects)| format!("Monkey {} inspected items {} times.", i, inspects))
+                .collect();
+            Ok(lines.join("\n"))
+        },
+    },
+];
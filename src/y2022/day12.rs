@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+
+use crate::repl::{self, Query};
+use crate::util::grid::Grid;
+use crate::util::search;
+use crate::util::point::{Direction, Point};
+
+/**
+ * The heights of the area, 0 for `a` to 25 for `z`, with the start `S` and the
+ * best signal `E` marked separately.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Heightmap {
+    pub heights: Grid<i32>,
+    pub start: Point,
+    pub end: Point,
+}
+
+/**
+ * Parse the input into a grid of heights and the start and end positions.
+ *
+ * The a-z characters are converted to 0-25, and the start and end positions are
+ * converted to 0 and 25 respectively.
+ */
+pub fn parse(input: &[String]) -> Result<Heightmap, String> {
+    let chars = Grid::parse(input, |c| match c {
+        'a'..='z' | 'S' | 'E' => Ok(c),
+        _ => Err(format!("'{}' is not a height", c)),
+    })?;
+    let start = Point::from(chars.position(|&c| c == 'S').ok_or("no start position")?);
+    let end = Point::from(chars.position(|&c| c == 'E').ok_or("no end position")?);
+    let heights = chars.map(|&c| match c {
+        'S' => 0,
+        'E' => 25,
+        c => c as i32 - 'a' as i32,
+    });
+    return Ok(Heightmap { heights, start, end });
+}
+
+/**
+ * Draws the heights as letters again, with `S` and `E` in their places.
+ */
+pub fn to_text(model: &Heightmap) -> String {
+    let mut letters = model.heights.map(|&h| (b'a' + h.clamp(0, 25) as u8) as char);
+    // an imported start or end off the grid is left out, parsing the text reports it
+    for (point, c) in [(model.start, 'S'), (model.end, 'E')] {
+        if let Some(cell) = point.to_pos().and_then(|pos| letters.get_mut(pos)) {
+            *cell = c;
+        }
+    }
+
+    let mut text = String::new();
+    for row in letters.rows() {
+        text.extend(row);
+        text.push('\n');
+    }
+    text
+}
+
+/**
+ * The positions one step away from `current` that are at most one higher.
+ */
+fn climbs(model: &Heightmap, current: Point) -> impl Iterator<Item = Point> + '_ {
+    let heightmap = &model.heights;
+    let height = heightmap[current];
+    Direction::ALL
+        .into_iter()
+        .map(move |direction| current + direction.vector())
+        .filter(move |&next| heightmap.get_point(next).is_some_and(|&next_height| next_height <= height + 1))
+}
+
+/**
+ * Returns the positions along a shortest path from the start to the end, both
+ * included, or `None` if the end cannot be reached.
+ */
+pub fn shortest_path(model: &Heightmap) -> Option<Vec<Point>> {
+    let search = search::bfs([model.start], |&current| climbs(model, current), |&current| current == model.end);
+    return search.path(&model.end);
+}
+
+/**
+ * Returns the number of steps from the start to every position, `None` where
+ * it cannot be reached.
+ */
+pub fn distances(model: &Heightmap) -> Grid<Option<i32>> {
+    let search = search::bfs([model.start], |&current| climbs(model, current), |_| false);
+    let mut steps = model.heights.map(|_| None);
+    for (point, distance) in search.distances {
+        steps[point] = Some(distance as i32);
+    }
+    return steps;
+}
+
+/**
+ * Returns the shortest path from the start to the end, `None` if the end cannot be reached.
+ */
+pub fn part1(model: &Heightmap) -> Option<i32> {
+    let search = search::bfs([model.start], |&current| climbs(model, current), |&current| current == model.end);
+    return search.goal_distance().map(|steps| steps as i32);
+}
+
+/**
+ * Returns the shortest path from the base level (0 or 'a') to the end, `None` if there is none.
+ *
+ * Searches from every position at the base level at once.
+ */
+pub fn part2(model: &Heightmap) -> Option<i32> {
+    let starts = model.heights.iter().filter(|&(_, &height)| height == 0).map(|(pos, _)| Point::from(pos));
+    let search = search::bfs(starts, |&current| climbs(model, current), |&current| current == model.end);
+    return search.goal_distance().map(|steps| steps as i32);
+}
+
+/**
+ * Reads a ROW COL pair of arguments as a position on the map.
+ */
+fn position(model: &Heightmap, args: &[&str]) -> Result<(usize, usize), String> {
+    let pos = (repl::arg(args, 0, "row")?, repl::arg(args, 1, "column")?);
+    if !model.heights.contains(pos) {
+        return Err(format!("{} {} is outside the {}x{} map", pos.0, pos.1, model.heights.height(), model.heights.width()));
+    }
+    Ok(pos)
+}
+
+pub const QUERIES: &[Query<Heightmap>] = &[
+    Query {
+        name: "distance",
+        args: "ROW COL",
+        help: "the fewest steps from the start to a position, counting from 0",
+        run: |model, args| {
+            let pos = position(model, args)?;
+            Ok(match distances(model)[pos] {
+                Some(steps) => format!("{} steps from the start", steps),
+                None => "cannot be reached from the start".to_string(),
+            })
+        },
+    },
+    Query {
+        name: "height",
+        args: "ROW COL",
+        help: "the height of a position as its letter",
+        run: |model, args| {
+            let pos = position(model, args)?;
+            Ok(((b'a' + model.heights[pos] as u8) as char).to_string())
+        },
+    },
+];
@@ -0,0 +1,169 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::util::lenient;
+
+/**
+ * A packet is either an integer or a list of packets.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Packet {
+    Int(i64),
+    List(Vec<Packet>),
+}
+
+/**
+ * Parses a packet such as `[1,[2,[3]],4]`, returning the packet and the unparsed rest.
+ */
+fn parse_packet(s: &str) -> Result<(Packet, &str), String> {
+    if let Some(mut rest) = s.strip_prefix('[') {
+        let mut items = Vec::new();
+        loop {
+            if let Some(after) = rest.strip_prefix(']') {
+                return Ok((Packet::List(items), after));
+            }
+            if let Some(after) = rest.strip_prefix(',') {
+                rest = after;
+            }
+            let (item, after) = parse_packet(rest)?;
+            items.push(item);
+            rest = after;
+        }
+    }
+
+    let digits = s.find(|c: char| !c.is_ascii_digit() && c != '-').unwrap_or(s.len());
+    let value = s[..digits]
+        .parse()
+        .map_err(|_| format!("expected an integer or a list at \"{}\"", s))?;
+    Ok((Packet::Int(value), &s[digits..]))
+}
+
+impl std::str::FromStr for Packet {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Packet, String> {
+        match parse_packet(s)? {
+            (packet, "") => Ok(packet),
+            (_, rest) => Err(format!("unexpected \"{}\" after the packet", rest)),
+        }
+    }
+}
+
+impl fmt::Display for Packet {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Packet::Int(value) => write!(f, "{}", value),
+            Packet::List(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
+        }
+    }
+}
+
+/**
+ * Compares two packets.
+ *
+ * Integers are compared by value, lists element by element and then by length.
+ * When only one side is an integer it is wrapped in a list first.
+ */
+fn compare(left: &Packet, right: &Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Int(l), Packet::Int(r)) => l.cmp(r),
+        (Packet::Int(_), Packet::List(_)) => compare(&Packet::List(vec![left.clone()]), right),
+        (Packet::List(_), Packet::Int(_)) => compare(left, &Packet::List(vec![right.clone()])),
+        (Packet::List(l), Packet::List(r)) => {
+            // if both are lists, compare each element
+            for (l, r) in l.iter().zip(r.iter()) {
+                let res = compare(l, r);
+                if res != Ordering::Equal {
+                    return res;
+                }
+            }
+
+            // if still no decision can be made, compare the length
+            l.len().cmp(&r.len())
+        }
+    }
+}
+
+/**
+ * Parses the input into pairs of packets separated by blank lines.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<(Packet, Packet)>, String> {
+    // the packets of each pair with the line the pair starts on
+    let mut groups = vec![(0, Vec::new())];
+    for (i, line) in input.iter().enumerate() {
+        if line.trim().is_empty() {
+            groups.push((i + 1, Vec::new()));
+        } else {
+            // a malformed packet leaves its pair one short, which drops the pair
+            match line.trim().parse::<Packet>() {
+                Ok(packet) => groups.last_mut().unwrap().1.push(packet),
+                Err(e) => lenient::skip(i, e)?,
+            }
+        }
+    }
+    groups.retain(|(_, group)| !group.is_empty());
+
+    let mut pairs = Vec::new();
+    for (index, (line, group)) in groups.into_iter().enumerate() {
+        match <[Packet; 2]>::try_from(group) {
+            Ok([left, right]) => pairs.push((left, right)),
+            Err(group) => lenient::skip(line, format!("pair {} has {} packets", index + 1, group.len()))?,
+        }
+    }
+    return Ok(pairs);
+}
+
+pub fn to_text(pairs: &[(Packet, Packet)]) -> String {
+    let pairs: Vec<String> = pairs.iter().map(|(left, right)| format!("{}\n{}\n", left, right)).collect();
+    pairs.join("\n")
+}
+
+/**
+ * Returns the sum of the 1-based indices of the pairs that are in the right order.
+ */
+pub fn part1(pairs: &[(Packet, Packet)]) -> usize {
+    let mut sum_indices = 0;
+    for (index, (left, right)) in pairs.iter().enumerate() {
+        if compare(left, right) == Ordering::Less {
+            sum_indices += index + 1;
+        }
+    }
+
+    return sum_indices;
+}
+
+/**
+ * Returns the decoder key, the product of the 1-based positions of the divider
+ * packets `[[2]]` and `[[6]]` once all packets are sorted.
+ */
+pub fn part2(pairs: &[(Packet, Packet)]) -> usize {
+    let divider_2 = Packet::List(vec![Packet::List(vec![Packet::Int(2)])]);
+    let divider_6 = Packet::List(vec![Packet::List(vec![Packet::Int(6)])]);
+
+    // [[2]] sorts before [[6]], so the latter starts one position further
+    let mut index_2 = 1;
+    let mut index_6 = 2;
+
+    for signal in pairs.iter().flat_map(|(left, right)| [left, right]) {
+        if compare(signal, &divider_2) == Ordering::Less {
+            index_2 += 1;
+        }
+        if compare(signal, &divider_6) == Ordering::Less {
+            index_6 += 1;
+        }
+    }
+
+    return index_2 * index_6;
+}
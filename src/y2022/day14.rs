@@ -0,0 +1,271 @@
+use std::convert::Infallible;
+
+use serde::{Deserialize, Serialize};
+
+use crate::scan;
+use crate::sim::Simulation;
+use crate::util::lenient;
+
+/**
+ * Width and height of the simulated cave.
+ */
+const EDGE_LENGTH: usize = 1000;
+
+/**
+ * Where the sand enters the cave, as (x, y).
+ */
+const SAND_SOURCE: (usize, usize) = (500, 0);
+
+/**
+ * The corners of one rock formation, as (x, y), joined by straight lines.
+ */
+pub type RockPath = Vec<(usize, usize)>;
+
+/**
+ * Parses each line of `x,y -> x,y -> ...` into a path of rock corners.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<RockPath>, String> {
+    // blank lines are skipped here rather than filtered out, so warnings keep the line numbers
+    let paths = lenient::parse_lines(input, |line| {
+        if line.trim().is_empty() {
+            return Ok(None);
+        }
+        let mut path = Vec::new();
+        for pair in line.split(" -> ") {
+            let (x, y): (usize, usize) = scan!(pair.trim(), "{},{}").map_err(|e| e.to_string())?;
+            // leave room for sand to roll past the rock
+            if x == 0 || x + 1 >= EDGE_LENGTH || y >= EDGE_LENGTH {
+                return Err(format!("rock at {},{} is outside the simulated cave", x, y));
+            }
+            // sand spreads at most one column per row, so on the floor of part 2,
+            // two rows below, it reaches y + 1 columns either side of the source
+            // and must still have a column beyond that to roll into
+            if y + 2 > SAND_SOURCE.0.min(EDGE_LENGTH - 1 - SAND_SOURCE.0) {
+                return Err(format!("rock at {},{} is too deep for the sand to fit in the simulated cave", x, y));
+            }
+            path.push((x, y));
+        }
+        Ok(Some(path))
+    })?;
+    Ok(paths.into_iter().flatten().collect())
+}
+
+pub fn to_text(paths: &[RockPath]) -> String {
+    paths
+        .iter()
+        .map(|path| {
+            let corners: Vec<String> = path.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+            corners.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+/**
+ * Places the rocks on the map and returns the map, indexed as `map[y][x]`.
+ */
+fn place_rocks(paths: &[RockPath]) -> Vec<Vec<char>> {
+    let mut map = vec![vec!['.'; EDGE_LENGTH]; EDGE_LENGTH];
+
+    // place the sand source
+    map[SAND_SOURCE.1][SAND_SOURCE.0] = '+';
+
+    // place the rocks between and including each pair of corners
+    for path in paths {
+        for i in 0..path.len() - 1 {
+            let (x0, y0) = path[i];
+            let (x1, y1) = path[i + 1];
+            for y in y0.min(y1)..=y0.max(y1) {
+                for x in x0.min(x1)..=x0.max(x1) {
+                    map[y][x] = '#';
+                }
+            }
+        }
+    }
+
+    return map;
+}
+
+/**
+ * Drops a single unit of sand and returns where it comes to rest, or `None`
+ * if it falls out of the cave instead, past `bottom`, the lowest row with
+ * rock or floor.
+ */
+fn drop_sand(map: &mut [Vec<char>], bottom: usize) -> Option<(usize, usize)> {
+    let (mut x, mut y) = SAND_SOURCE;
+
+    loop {
+        // nothing left below, the sand falls forever
+        if y >= bottom {
+            return None;
+        }
+
+        if map[y + 1][x] == '.' {
+            y += 1;
+        } else if map[y + 1][x - 1] == '.' {
+            x -= 1;
+            y += 1;
+        } else if map[y + 1][x + 1] == '.' {
+            x += 1;
+            y += 1;
+        } else {
+            map[y][x] = 'o';
+            return Some((x, y));
+        }
+    }
+}
+
+/**
+ * The cave indexed as `map[y][x]`: `.` is air, `#` rock, `o` sand at rest,
+ * `+` the source and `=` the floor.
+ */
+pub type Cave = Vec<Vec<char>>;
+
+/**
+ * Crops the cave to the part that holds anything but air and floor.
+ */
+pub fn draw(cave: &Cave) -> String {
+    let interesting = |c: char| c != '.' && c != '=';
+    let columns: Vec<usize> = cave
+        .iter()
+        .flat_map(|row| row.iter().enumerate().filter(|(_, &c)| interesting(c)).map(|(x, _)| x))
+        .collect();
+    let min_x = columns.iter().min().copied().unwrap_or(0).saturating_sub(1);
+    let max_x = columns.iter().max().copied().unwrap_or(0) + 1;
+    let max_y = cave.iter().rposition(|row| row.iter().any(|&c| c != '.')).unwrap_or(0);
+
+    let rows: Vec<String> = cave[..=max_y]
+        .iter()
+        .map(|row| row[min_x..=max_x.min(row.len() - 1)].iter().collect())
+        .collect();
+    rows.join("\n")
+}
+
+/**
+ * Where the sand poured so far has come to rest.
+ */
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SandState {
+    /// Each unit of sand at rest, as (x, y), in the order they came to rest.
+    pub sand: Vec<(usize, usize)>,
+    /// Whether sand has started falling into the abyss.
+    pub overflowing: bool,
+}
+
+/**
+ * Pours sand into the cave one unit per step until it either falls into the
+ * abyss or, with a floor two rows below the lowest rock, blocks the source.
+ */
+pub struct SandSimulation {
+    /// The cave without any sand.
+    empty: Cave,
+    cave: Cave,
+    /// The lowest row with rock or floor.
+    bottom: usize,
+    state: SandState,
+}
+
+impl SandSimulation {
+    pub fn new(paths: &[RockPath], with_floor: bool) -> SandSimulation {
+        let mut map = place_rocks(paths);
+        let mut bottom = map.iter().rposition(|row| row.contains(&'#')).unwrap_or(0);
+
+        if with_floor {
+            // add the floor below the lowest rock
+            bottom += 2;
+            for cell in map[bottom].iter_mut() {
+                *cell = '=';
+            }
+        }
+
+        SandSimulation {
+            cave: map.clone(),
+            empty: map,
+            bottom,
+            state: SandState {
+                sand: Vec::new(),
+                overflowing: false,
+            },
+        }
+    }
+
+    pub fn cave(&self) -> &Cave {
+        &self.cave
+    }
+}
+
+impl Simulation for SandSimulation {
+    type State = SandState;
+    type Error = Infallible;
+
+    fn step(&mut self) -> Result<bool, Infallible> {
+        if self.state.overflowing || self.cave[SAND_SOURCE.1][SAND_SOURCE.0] == 'o' {
+            return Ok(false);
+        }
+        match drop_sand(&mut self.cave, self.bottom) {
+            Some(rest) => self.state.sand.push(rest),
+            None => self.state.overflowing = true,
+        }
+        Ok(true)
+    }
+
+    fn state(&self) -> &SandState {
+        &self.state
+    }
+
+    fn restore(&mut self, state: SandState) {
+        self.cave = self.empty.clone();
+        for &(x, y) in &state.sand {
+            self.cave[y][x] = 'o';
+        }
+        self.state = state;
+    }
+
+    fn render(&self) -> String {
+        draw(&self.cave)
+    }
+}
+
+/**
+ * Pours sand into the cave until it either falls into the abyss or, with a
+ * floor two rows below the lowest rock, blocks the source, and returns the
+ * cave at that point.
+ */
+pub fn pour_sand(paths: &[RockPath], with_floor: bool) -> Cave {
+    let mut simulation = SandSimulation::new(paths, with_floor);
+    let Ok(()) = simulation.run();
+    return simulation.cave;
+}
+
+/**
+ * The simulation of part 1, without a floor.
+ */
+pub fn part1_simulation(paths: &[RockPath]) -> SandSimulation {
+    SandSimulation::new(paths, false)
+}
+
+/**
+ * The simulation of part 2, with a floor.
+ */
+pub fn part2_simulation(paths: &[RockPath]) -> SandSimulation {
+    SandSimulation::new(paths, true)
+}
+
+/**
+ * Returns the number of units of sand that come to rest before sand starts
+ * falling into the abyss.
+ */
+pub fn part1(paths: &[RockPath]) -> i32 {
+    let mut simulation = part1_simulation(paths);
+    let Ok(()) = simulation.run();
+    return simulation.state.sand.len() as i32;
+}
+
+/**
+ * Returns the number of units of sand that come to rest before the source is
+ * blocked, with an infinite floor two rows below the lowest rock.
+ */
+pub fn part2(paths: &[RockPath]) -> i32 {
+    let mut simulation = part2_simulation(paths);
+    let Ok(()) = simulation.run();
+    return simulation.state.sand.len() as i32;
+}
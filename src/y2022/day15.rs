@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::cancel;
+use crate::scan;
+use crate::util::interval::{Interval, IntervalSet};
+use crate::util::lenient;
+use crate::util::point::Point;
+
+/**
+ * The row inspected by part 1.
+ */
+const ROW: i64 = 2000000;
+
+/**
+ * The search space of part 2 spans `0..=SEARCH_SIZE` in both coordinates.
+ */
+const SEARCH_SIZE: i64 = 4000000;
+
+/**
+ * A sensor and the closest beacon it detects.
+ */
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sensor {
+    pub position: Point,
+    pub beacon: Point,
+}
+
+impl Sensor {
+    /**
+     * How far the sensor covers, no other beacon is within this distance.
+     */
+    pub fn radius(&self) -> i64 {
+        self.position.manhattan(self.beacon)
+    }
+}
+
+/**
+ * Parses lines of the form
+ * `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`.
+ */
+pub fn parse(input: &[String]) -> Result<Vec<Sensor>, String> {
+    let mut sensors = Vec::new();
+    for (i, line) in input.iter().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match scan!(line.trim(), "Sensor at x={}, y={}: closest beacon is at x={}, y={}") {
+            Ok((sx, sy, bx, by)) => sensors.push(Sensor {
+                position: Point::new(sx, sy),
+                beacon: Point::new(bx, by),
+            }),
+            Err(e) => lenient::skip(i, e.to_string())?,
+        }
+    }
+    return Ok(sensors);
+}
+
+pub fn to_text(sensors: &[Sensor]) -> String {
+    sensors
+        .iter()
+        .map(|s| {
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                s.position.x, s.position.y, s.beacon.x, s.beacon.y
+            )
+        })
+        .collect()
+}
+
+/**
+ * Returns the x positions covered on row `y`.
+ */
+fn coverage(sensors: &[Sensor], y: i64) -> IntervalSet {
+    sensors
+        .iter()
+        .filter_map(|sensor| {
+            // negative for a sensor that does not reach the row, leaving no interval
+            let x_radius = sensor.radius() - (y - sensor.position.y).abs();
+            Interval::new(sensor.position.x - x_radius, sensor.position.x + x_radius)
+        })
+        .collect()
+}
+
+/**
+ * Counts the positions on row `y` that cannot contain a beacon.
+ */
+pub fn part1_at(sensors: &[Sensor], y: i64) -> i64 {
+    let covered = coverage(sensors, y).len() as i64;
+
+    // existing sensors and beacons on the row are always covered, don't count them
+    let mut occupied = HashSet::new();
+    for sensor in sensors {
+        for position in [sensor.position, sensor.beacon] {
+            if position.y == y {
+                occupied.insert(position.x);
+            }
+        }
+    }
+
+    return covered - occupied.len() as i64;
+}
+
+/**
+ * Returns the tuning frequency `x * 4000000 + y` of the only position in the
+ * search space that no sensor covers, found as the gap between two covered
+ * ranges on a row, or `None` if there is none.
+ */
+pub fn part2_within(sensors: &[Sensor], search_size: i64) -> Option<i64> {
+    for y in 0..=search_size {
+        cancel::checkpoint();
+
+        // find the gap between two covered ranges, there should only be one
+        for gap in coverage(sensors, y).gaps() {
+            let x = gap.start();
+            if (0..=search_size).contains(&x) {
+                return Some((x * 4000000) + y);
+            }
+        }
+    }
+
+    return None;
+}
+
+/**
+ * Counts the positions on row 2000000 that cannot contain a beacon.
+ */
+pub fn part1(sensors: &[Sensor]) -> i64 {
+    part1_at(sensors, ROW)
+}
+
+/**
+ * Finds the distress beacon within `0..=4000000` in both coordinates.
+ */
+pub fn part2(sensors: &[Sensor]) -> Option<i64> {
+    part2_within(sensors, SEARCH_SIZE)
+}
@@ -0,0 +1,116 @@
+//! Advent of Code 2022.
+//!
+//! Every day module has the same shape: model types for the puzzle input, a
+//! `parse` function turning the input lines into the model, and `part1` and
+//! `part2` computing the answers from it. None of them print anything. The
+//! models are serde-serializable and `to_text` writes one back as puzzle text.
+
+use crate::registry::{
+    converter, querier, simulator, solution, zero_copy, Converter, Querier, Simulator, Solution, ZeroCopy,
+};
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+
+pub const YEAR: u16 = 2022;
+
+pub const SOLUTIONS: &[Solution] = &[
+    solution!(YEAR, 1, 1, day01::part1),
+    solution!(YEAR, 1, 2, day01::part2),
+    solution!(YEAR, 2, 1, day02::part1),
+    solution!(YEAR, 2, 2, day02::part2),
+    solution!(YEAR, 3, 1, day03::part1),
+    solution!(YEAR, 3, 2, day03::part2),
+    solution!(YEAR, 4, 1, day04::part1),
+    solution!(YEAR, 4, 2, day04::part2),
+    solution!(YEAR, 5, 1, day05::part1),
+    solution!(YEAR, 5, 2, day05::part2),
+    solution!(YEAR, 6, 1, day06::part1),
+    solution!(YEAR, 6, 1, "fast", day06::part1_fast),
+    solution!(YEAR, 6, 2, day06::part2),
+    solution!(YEAR, 6, 2, "fast", day06::part2_fast),
+    solution!(YEAR, 7, 1, day07::part1),
+    solution!(YEAR, 7, 1, "fast", day07::part1_fast),
+    solution!(YEAR, 7, 2, day07::part2),
+    solution!(YEAR, 7, 2, "fast", day07::part2_fast),
+    solution!(YEAR, 8, 1, day08::part1),
+    solution!(YEAR, 8, 1, "fast", day08::part1_fast),
+    solution!(YEAR, 8, 2, day08::part2),
+    solution!(YEAR, 8, 2, "fast", day08::part2_fast),
+    solution!(YEAR, 9, 1, day09::part1),
+    solution!(YEAR, 9, 2, day09::part2),
+    solution!(YEAR, 10, 1, day10::part1),
+    solution!(YEAR, 10, 2, day10::part2),
+    solution!(YEAR, 11, 1, day11::part1),
+    solution!(YEAR, 11, 2, day11::part2),
+    solution!(YEAR, 12, 1, day12::part1),
+    solution!(YEAR, 12, 2, day12::part2),
+    solution!(YEAR, 13, 1, day13::part1),
+    solution!(YEAR, 13, 2, day13::part2),
+    solution!(YEAR, 14, 1, day14::part1),
+    solution!(YEAR, 14, 2, day14::part2),
+    solution!(YEAR, 15, 1, day15::part1),
+    solution!(YEAR, 15, 2, day15::part2),
+];
+
+pub const CONVERTERS: &[Converter] = &[
+    converter!(YEAR, 1, day01),
+    converter!(YEAR, 2, day02),
+    converter!(YEAR, 3, day03),
+    converter!(YEAR, 4, day04),
+    converter!(YEAR, 5, day05),
+    converter!(YEAR, 6, day06),
+    converter!(YEAR, 7, day07),
+    converter!(YEAR, 8, day08),
+    converter!(YEAR, 9, day09),
+    converter!(YEAR, 10, day10),
+    converter!(YEAR, 11, day11),
+    converter!(YEAR, 12, day12),
+    converter!(YEAR, 13, day13),
+    converter!(YEAR, 14, day14),
+    converter!(YEAR, 15, day15),
+];
+
+pub const SIMULATORS: &[Simulator] = &[
+    simulator!(YEAR, 9, 1, day09::part1_simulation),
+    simulator!(YEAR, 9, 2, day09::part2_simulation),
+    simulator!(YEAR, 10, 1, day10::simulation),
+    simulator!(YEAR, 10, 2, day10::simulation),
+    simulator!(YEAR, 11, 1, day11::part1_simulation),
+    simulator!(YEAR, 11, 2, day11::part2_simulation),
+    simulator!(YEAR, 14, 1, day14::part1_simulation),
+    simulator!(YEAR, 14, 2, day14::part2_simulation),
+];
+
+pub const QUERIERS: &[Querier] = &[
+    querier!(YEAR, 5, day05),
+    querier!(YEAR, 7, day07),
+    querier!(YEAR, 11, day11),
+    querier!(YEAR, 12, day12),
+];
+
+pub const ZERO_COPY: &[ZeroCopy] = &[
+    zero_copy!(YEAR, 1, 1, day01::part1),
+    zero_copy!(YEAR, 1, 2, day01::part2),
+    zero_copy!(YEAR, 2, 1, day02::part1),
+    zero_copy!(YEAR, 2, 2, day02::part2),
+    zero_copy!(YEAR, 4, 1, day04::part1),
+    zero_copy!(YEAR, 4, 2, day04::part2),
+    zero_copy!(YEAR, 6, 1, day06::part1),
+    zero_copy!(YEAR, 6, 2, day06::part2),
+    zero_copy!(YEAR, 10, 1, day10::part1),
+    zero_copy!(YEAR, 10, 2, day10::part2),
+];
@@ -0,0 +1,44 @@
+use aoc::util::input::read_lines;
+use aoc::y2022::{day05, day10, day11, day12};
+
+mod common;
+
+use common::{example, lines};
+
+#[test]
+fn models_are_exposed() {
+    let crates = day05::parse(&example(5)).unwrap();
+    assert_eq!(crates.stacks, vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+    assert_eq!(crates.moves[0], day05::Move { count: 1, from: 2, to: 1 });
+    assert_eq!(day05::part1(&crates).unwrap(), "CMZ");
+
+    let monkeys = day11::parse(&example(11)).unwrap();
+    assert_eq!(monkeys.len(), 4);
+    assert_eq!(monkeys[0].operator, day11::Operator::Multiply);
+    assert_eq!(monkeys[0].operands, (day11::Operand::Old, day11::Operand::Value(19)));
+
+    let heightmap = day12::parse(&example(12)).unwrap();
+    assert_eq!(day12::part1(&heightmap), Some(31));
+}
+
+#[test]
+fn crt_is_returned() {
+    let program = day10::parse(&example(10)).unwrap();
+    let crt = day10::part2(&program);
+    assert_eq!(crt.lines().count(), 6);
+    assert!(crt.starts_with("##..##..##..##..##..##..##..##..##..##..\n"));
+}
+
+#[test]
+fn malformed_input_is_an_error() {
+    assert!(day05::parse(&lines("move 1 from 1 to 2")).is_err());
+    assert!(day10::parse(&lines("addx one")).is_err());
+    assert!(day11::parse(&lines("Monkey 0:\n  Starting items: 1")).is_err());
+    assert!(day12::parse(&lines("abc\nSbE\nab?")).is_err());
+}
+
+#[test]
+fn missing_files_are_errors() {
+    let error = read_lines(std::env::temp_dir().join("aoc-no-such-input.txt")).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::NotFound);
+}
@@ -0,0 +1,81 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc::cancel::{self, Cancelled, Token};
+use aoc::registry;
+use aoc::runner::{self, Failure};
+use aoc::server;
+use aoc::y2022::day11::{self, Monkey, Operand, Operator};
+
+mod common;
+
+use common::example;
+
+/**
+ * Spins at checkpoints until it is stopped.
+ */
+fn spin() {
+    loop {
+        cancel::checkpoint();
+        thread::yield_now();
+    }
+}
+
+#[test]
+fn checkpoints_do_nothing_outside_of_run() {
+    cancel::checkpoint();
+    assert_eq!(cancel::run(&Token::new(), || 42), Ok(42));
+    cancel::checkpoint();
+}
+
+#[test]
+fn deadline_stops_the_closure() {
+    let start = Instant::now();
+    let result = cancel::run(&Token::with_timeout(Duration::from_millis(20)), spin);
+    assert_eq!(result, Err(Cancelled::TimedOut));
+    assert!(start.elapsed() >= Duration::from_millis(20));
+}
+
+#[test]
+fn cancel_stops_the_closure_from_another_thread() {
+    let token = Token::new();
+    let canceller = token.clone();
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(20));
+        canceller.cancel();
+    });
+    assert_eq!(cancel::run(&token, spin), Err(Cancelled::Cancelled));
+}
+
+#[test]
+fn runner_reports_a_timeout() {
+    let solution = registry::find(2022, 11, 2).unwrap();
+    let outcome = runner::run_with(solution, &example(11), &Token::with_timeout(Duration::ZERO));
+    assert_eq!(outcome.answer, Err(Failure::Stopped(Cancelled::TimedOut)));
+
+    let outcome = runner::run_with(solution, &example(11), &Token::with_timeout(Duration::from_secs(60)));
+    assert_eq!(outcome.answer, Ok("2713310158".to_string()));
+}
+
+#[test]
+fn server_answers_a_timeout_with_504() {
+    let body = example(11).join("\n");
+    let response = server::handle_within("POST", "/2022/day/11/part/2", &body, Duration::ZERO);
+    assert_eq!(response.status, 504);
+    assert!(response.body.contains("\"error\":\"solver timed out\""), "{}", response.body);
+}
+
+#[test]
+fn a_round_that_never_ends_is_stopped() {
+    // the monkey keeps the item, so its first turn never ends
+    let monkeys = [Monkey {
+        items: vec![1],
+        operator: Operator::Add,
+        operands: (Operand::Old, Operand::Value(0)),
+        divisor: 2,
+        if_true: 0,
+        if_false: 0,
+    }];
+    let result = cancel::run(&Token::with_timeout(Duration::from_millis(20)), || day11::part1(&monkeys));
+    assert_eq!(result, Err(Cancelled::TimedOut));
+}
@@ -0,0 +1,27 @@
+//! Helpers shared by the integration tests.
+
+// every test crate includes this module but uses only some of it
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+use aoc::util::input::{puzzle_path, read_lines};
+
+pub fn lines(text: &str) -> Vec<String> {
+    text.lines().map(|s| s.to_string()).collect()
+}
+
+/**
+ * The path of a 2022 day's example input.
+ */
+pub fn example_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join(puzzle_path(2022, day, "test.txt"))
+}
+
+pub fn example(day: u8) -> Vec<String> {
+    read_lines(example_path(day)).unwrap()
+}
+
+pub fn example_text(day: u8) -> String {
+    std::fs::read_to_string(example_path(day)).unwrap()
+}
@@ -0,0 +1,88 @@
+use aoc::convert::{self, Format};
+use aoc::gen::{self, Size};
+use aoc::registry;
+use aoc::util::rng::Rng;
+use aoc::y2022::{day04, day07, day11};
+
+mod common;
+
+use common::{example_path, lines};
+
+fn answers(day: u8, text: &str) -> Vec<Result<String, String>> {
+    registry::for_day(2022, day).map(|s| (s.solve)(&lines(text))).collect()
+}
+
+#[test]
+fn examples_survive_a_round_trip_through_json() {
+    for day in 1..=15 {
+        let path = example_path(day);
+        let Ok(text) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        let converter = registry::converter_for(2022, day).unwrap();
+
+        let json = (converter.convert)(&text, Format::Text, Format::Json).unwrap();
+        let back = (converter.convert)(&json, Format::Json, Format::Text).unwrap();
+        assert_eq!(answers(day, &back), answers(day, &text), "day {}", day);
+        assert_eq!((converter.convert)(&back, Format::Text, Format::Json).unwrap(), json, "day {}", day);
+    }
+}
+
+#[test]
+fn pairs_are_csv_rows() {
+    let pairs = day04::parse(&lines("2-4,6-8\n2-3,4-5\n")).unwrap();
+    let csv = convert::encode(&pairs, Format::Csv).unwrap();
+    assert_eq!(csv, "0.0,0.1,1.0,1.1\n2,4,6,8\n2,3,4,5\n");
+
+    let decoded: Vec<day04::Pair> = convert::decode(&csv, Format::Csv).unwrap();
+    assert_eq!(decoded, pairs);
+    assert_eq!(day04::to_text(&decoded), "2-4,6-8\n2-3,4-5\n");
+}
+
+#[test]
+fn filesystem_is_a_json_tree() {
+    let filesystem = day07::parse(&lines("$ cd /\n$ ls\ndir a\n14848514 b.txt\n$ cd a\n$ ls\n29116 f\n")).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&convert::encode(&filesystem, Format::Json).unwrap()).unwrap();
+    assert_eq!(json["root"]["files"]["b.txt"], 14848514);
+    assert_eq!(json["root"]["dirs"]["a"]["files"]["f"], 29116);
+    assert_eq!(day07::part1(&filesystem).unwrap(), 29116);
+}
+
+#[test]
+fn deep_filesystems_survive_a_round_trip_through_json() {
+    let converter = registry::converter_for(2022, 7).unwrap();
+    let generated = gen::generate(2022, 7, &mut Rng::new(0), Size::MEDIUM).unwrap();
+    // far deeper than serde_json reads by default
+    let chain: String = (0..300).map(|i| format!("$ cd d{}\n$ ls\n{} f\n", i, i + 1)).collect();
+    for text in [generated, format!("$ cd /\n{}", chain)] {
+        let json = (converter.convert)(&text, Format::Text, Format::Json).unwrap();
+        let back = (converter.convert)(&json, Format::Json, Format::Text).unwrap();
+        assert_eq!(answers(7, &back), answers(7, &text));
+        assert_eq!((converter.convert)(&back, Format::Text, Format::Json).unwrap(), json);
+    }
+}
+
+#[test]
+fn monkeys_are_read_from_json() {
+    let json = r#"[
+        {"items": [79, 98], "operator": "multiply", "operands": ["old", {"value": 19}],
+         "divisor": 23, "if_true": 1, "if_false": 1},
+        {"items": [54], "operator": "add", "operands": ["old", "old"],
+         "divisor": 19, "if_true": 0, "if_false": 0}
+    ]"#;
+    let monkeys: Vec<day11::Monkey> = convert::decode(json, Format::Json).unwrap();
+    assert_eq!(monkeys[1].operands, (day11::Operand::Old, day11::Operand::Old));
+
+    let text = day11::to_text(&monkeys);
+    assert_eq!(day11::parse(&lines(&text)).unwrap(), monkeys);
+}
+
+#[test]
+fn unsupported_shapes_are_errors() {
+    let converter = registry::converter_for(2022, 7).unwrap();
+    let error = (converter.convert)("$ cd /\n$ ls\n1 a\n", Format::Text, Format::Csv).unwrap_err();
+    assert!(error.contains("CSV"), "{}", error);
+
+    assert!((converter.convert)("{\"root\": 3}", Format::Json, Format::Text).is_err());
+    assert!("yaml".parse::<Format>().is_err());
+}
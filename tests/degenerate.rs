@@ -0,0 +1,108 @@
+//! Inputs too small or too short for a puzzle get an error, not a panic.
+
+use aoc::registry::{self, NO_ANSWER};
+use aoc::runner;
+use aoc::y2022::{day01, day03, day06, day08, day11, day14, day15};
+
+mod common;
+
+use common::lines;
+
+fn answer(day: u8, part: u8, text: &str) -> Result<String, String> {
+    (registry::find(2022, day, part).unwrap().solve)(&lines(text))
+}
+
+#[test]
+fn no_solver_panics_on_tiny_inputs() {
+    for text in ["", "\n", "\n\n", "1", "1\n2\n", "abc", "ab\n"] {
+        for solution in registry::all_variants().filter(|s| s.year == 2022) {
+            let outcome = runner::cross_check(&[solution], &lines(text), None).outcomes.remove(0).1;
+            assert!(
+                !outcome.answer.as_ref().is_err_and(|f| f.is_panic()),
+                "day {} part {} variant {} on {:?}: {:?}",
+                solution.day,
+                solution.part,
+                solution.variant,
+                text,
+                outcome.answer
+            );
+        }
+    }
+}
+
+#[test]
+fn fewer_than_three_elves_have_no_top_three() {
+    let elves = day01::parse(&lines("1\n2\n\n3")).unwrap();
+    assert_eq!(elves.len(), 2);
+    assert_eq!(day01::part1(&elves), Ok(Some(3)));
+    assert_eq!(day01::part2(&elves), Ok(None));
+    assert_eq!(answer(1, 2, "1\n\n2\n"), Err(NO_ANSWER.to_string()));
+
+    // the last Elf counts without a blank line after it
+    assert_eq!(answer(1, 2, "1\n\n2\n\n3"), Ok("6".to_string()));
+    assert_eq!(answer(1, 1, ""), Err(NO_ANSWER.to_string()));
+}
+
+#[test]
+fn rucksacks_come_in_groups_of_three() {
+    let rucksacks = day03::parse(&lines("vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nab")).unwrap();
+    assert_eq!(day03::part1(&rucksacks), 16 + 38 + 42);
+    assert!(day03::part2(&rucksacks).unwrap_err().contains("groups of three"));
+    assert_eq!(day03::part2(&rucksacks[..3]), Ok(18));
+}
+
+#[test]
+fn short_streams_have_no_marker() {
+    for text in ["", "a", "abc", "abcdefghijklm"] {
+        let stream = day06::parse(&lines(text)).unwrap_or_default();
+        assert_eq!(day06::part2(&stream), None, "{:?}", text);
+        assert_eq!(day06::part2_fast(&stream), None, "{:?}", text);
+    }
+    let stream = day06::parse(&lines("abc")).unwrap();
+    assert_eq!((day06::part1(&stream), day06::part1_fast(&stream)), (None, None));
+    assert_eq!(answer(6, 1, "abcd"), Ok("4".to_string()));
+}
+
+#[test]
+fn an_empty_forest_has_no_scenic_score() {
+    let forest = day08::parse(&[]).unwrap();
+    assert_eq!(day08::part1(&forest), 0);
+    assert_eq!((day08::part2(&forest), day08::part2_fast(&forest)), (None, None));
+}
+
+#[test]
+fn beacons_are_only_found_between_sensors() {
+    let sensors = day15::parse(&lines("Sensor at x=0, y=0: closest beacon is at x=0, y=1")).unwrap();
+    assert_eq!(day15::part2_within(&sensors, 4), None);
+    assert_eq!(day15::part2_within(&[], 4), None);
+
+    // the first row already has a gap between the two sensors
+    let apart = "Sensor at x=0, y=0: closest beacon is at x=1, y=0\nSensor at x=4, y=0: closest beacon is at x=5, y=0";
+    assert_eq!(day15::part2_within(&day15::parse(&lines(apart)).unwrap(), 4), Some(2 * 4000000));
+}
+
+#[test]
+fn monkeys_cannot_throw_to_themselves() {
+    let notes = |if_false: usize| {
+        format!(
+            "Monkey 0:\n  Starting items: 79\n  Operation: new = old + 1\n  Test: divisible by 2\n    If true: throw to monkey 1\n    If false: throw to monkey {}\n\n\
+             Monkey 1:\n  Starting items: 54\n  Operation: new = old * 2\n  Test: divisible by 3\n    If true: throw to monkey 0\n    If false: throw to monkey 0",
+            if_false
+        )
+    };
+    assert_eq!(day11::parse(&lines(&notes(1))).unwrap().len(), 2);
+    assert_eq!(day11::parse(&lines(&notes(0))), Err("monkey 0 throws to itself".to_string()));
+    assert_eq!(answer(11, 1, &notes(0)), Err("monkey 0 throws to itself".to_string()));
+}
+
+#[test]
+fn the_sand_pile_fits_in_the_cave() {
+    let error = day14::parse(&lines("499,600 -> 501,600")).unwrap_err();
+    assert!(error.contains("too deep"), "{}", error);
+    assert!(day14::parse(&lines("499,498 -> 501,498")).is_err());
+
+    // the deepest rock allowed, the sand falls past it on both sides
+    let paths = day14::parse(&lines("500,497 -> 500,497")).unwrap();
+    assert_eq!(day14::part1(&paths), 0);
+    assert_eq!(answer(14, 1, "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9"), Ok("24".to_string()));
+}
@@ -0,0 +1,90 @@
+use std::path::Path;
+use std::process::Command;
+
+use aoc::difftest;
+use aoc::gen;
+use aoc::gen::Size;
+use aoc::util::input::puzzle_path;
+use aoc::util::rng::Rng;
+
+mod common;
+
+use common::example_text;
+
+/**
+ * Whether the Python scripts can be run. Without python3 the tests fail, so
+ * that they don't pass without checking anything, unless `AOC_SKIP_PYTHON` is
+ * set to skip them on purpose.
+ */
+fn python_available() -> bool {
+    if Command::new("python3").arg("--version").output().is_ok() {
+        return true;
+    }
+    if std::env::var_os("AOC_SKIP_PYTHON").is_none() {
+        panic!("python3 not found, set AOC_SKIP_PYTHON=1 to skip the differential tests");
+    }
+    eprintln!("python3 not found, skipping as AOC_SKIP_PYTHON is set");
+    false
+}
+
+/**
+ * Checks a day's Rust port against its Python script on the example input and
+ * a few generated ones.
+ */
+fn check_day(day: u8) {
+    if !python_available() {
+        return;
+    }
+
+    let script = puzzle_path(2022, day, &format!("day{}.py", day));
+    let script = Path::new(env!("CARGO_MANIFEST_DIR")).join(script);
+    let mut inputs = vec![example_text(day)];
+    for seed in 0..3 {
+        inputs.push(gen::generate(2022, day, &mut Rng::new(seed), Size::SMALL).unwrap());
+    }
+
+    for input in inputs {
+        let disagreements = difftest::compare(2022, day, &script, &input);
+        assert!(disagreements.is_empty(), "{:#?}", disagreements);
+    }
+}
+
+#[test]
+fn day13_matches_python() {
+    check_day(13);
+}
+
+#[test]
+fn day14_matches_python() {
+    check_day(14);
+}
+
+#[test]
+fn day15_matches_python() {
+    check_day(15);
+}
+
+#[test]
+fn reports_disagreements() {
+    if !python_available() {
+        return;
+    }
+    // the day 14 script answered on a day 13 input fails while the Rust side succeeds
+    let script = Path::new(env!("CARGO_MANIFEST_DIR")).join(puzzle_path(2022, 14, "day14.py"));
+    let disagreements = difftest::compare(2022, 13, &script, "[1]\n[2]\n");
+    assert_eq!(disagreements.len(), 2);
+    assert_eq!(disagreements[0].rust, Ok("1".to_string()));
+    assert!(disagreements[0].python.is_err());
+}
+
+#[test]
+fn failures_on_both_sides_are_reported() {
+    if !python_available() {
+        return;
+    }
+    // neither side can read this, and each says so differently
+    let script = Path::new(env!("CARGO_MANIFEST_DIR")).join(puzzle_path(2022, 13, "day13.py"));
+    let disagreements = difftest::compare(2022, 13, &script, "[1\n[2]\n");
+    assert_eq!(disagreements.len(), 2);
+    assert!(disagreements.iter().all(|d| d.rust.is_err() && d.python.is_err()), "{:#?}", disagreements);
+}
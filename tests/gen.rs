@@ -0,0 +1,70 @@
+use aoc::gen::{self, Size};
+use aoc::registry;
+use aoc::runner;
+use aoc::util::rng::Rng;
+
+mod common;
+
+use common::lines;
+
+#[test]
+fn generators_are_deterministic() {
+    for day in 1..=15 {
+        let a = gen::generate(2022, day, &mut Rng::new(7), Size::SMALL).unwrap();
+        let b = gen::generate(2022, day, &mut Rng::new(7), Size::SMALL).unwrap();
+        let c = gen::generate(2022, day, &mut Rng::new(8), Size::SMALL).unwrap();
+        assert_eq!(a, b, "day {}", day);
+        assert_ne!(a, c, "day {}", day);
+    }
+    assert!(gen::generate(2022, 25, &mut Rng::new(0), Size::SMALL).is_none());
+}
+
+#[test]
+fn generated_inputs_are_solvable() {
+    // day 15 part 2 scans millions of rows, it has its own check in difftest.rs
+    for solution in registry::all().filter(|s| s.year == 2022 && s.day != 15) {
+        for seed in 0..3 {
+            let input = gen::generate(2022, solution.day, &mut Rng::new(seed), Size::SMALL).unwrap();
+            let outcome = runner::run(solution, &lines(&input));
+            assert!(
+                outcome.answer.is_ok(),
+                "day {} part {} seed {}: {:?}",
+                solution.day,
+                solution.part,
+                seed,
+                outcome.answer
+            );
+        }
+    }
+}
+
+#[test]
+fn generated_filesystems_fit_on_the_disk() {
+    for size in [Size::SMALL, Size::MEDIUM, Size::LARGE] {
+        for seed in 0..5 {
+            let input = gen::generate(2022, 7, &mut Rng::new(seed), size).unwrap();
+            for solution in registry::for_day(2022, 7) {
+                let outcome = runner::run(solution, &lines(&input));
+                assert!(
+                    outcome.answer.is_ok(),
+                    "size {} seed {} part {}: {:?}",
+                    size.0,
+                    seed,
+                    solution.part,
+                    outcome.answer
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn sizes_scale_up() {
+    assert_eq!("large".parse(), Ok(Size::LARGE));
+    assert_eq!("250".parse(), Ok(Size(250)));
+    assert!("0".parse::<Size>().is_err());
+
+    let small = gen::generate(2022, 9, &mut Rng::new(1), Size::SMALL).unwrap();
+    let medium = gen::generate(2022, 9, &mut Rng::new(1), Size::MEDIUM).unwrap();
+    assert_eq!(medium.lines().count(), 10 * small.lines().count());
+}
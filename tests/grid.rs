@@ -0,0 +1,40 @@
+use aoc::util::grid::Grid;
+
+fn sample() -> Grid<char> {
+    Grid::parse(&["abc", "def"], Ok).unwrap()
+}
+
+#[test]
+fn parses_rows_of_characters() {
+    let grid = sample();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(1, 2)], 'f');
+    assert_eq!(grid.get((2, 0)), None);
+    assert!(Grid::parse(&["ab", "c"], Ok).is_err());
+    assert!(Grid::<char>::parse(&[] as &[&str], Ok).unwrap().is_empty());
+}
+
+#[test]
+fn neighbours_stay_in_bounds() {
+    let grid = sample();
+    let mut corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+    corner.sort();
+    assert_eq!(corner, vec![(0, 1), (1, 0)]);
+    assert_eq!(grid.neighbours8((0, 1)).count(), 5);
+}
+
+#[test]
+fn iterates_rows_columns_and_rays() {
+    let grid = sample();
+    assert_eq!(grid.row(1), &['d', 'e', 'f']);
+    assert_eq!(grid.column(1).collect::<String>(), "be");
+    assert_eq!(grid.ray((0, 0), (0, 1)).map(|p| grid[p]).collect::<String>(), "bc");
+    assert_eq!(grid.ray((0, 0), (-1, 0)).count(), 0);
+    assert_eq!(grid.position(|&c| c == 'e'), Some((1, 1)));
+}
+
+#[test]
+fn displays_one_line_per_row() {
+    assert_eq!(sample().to_string(), "abc\ndef\n");
+    assert_eq!(sample().map(|&c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF\n");
+}
@@ -0,0 +1,60 @@
+use aoc::util::interval::{Interval, IntervalSet};
+
+fn interval(start: i64, end: i64) -> Interval {
+    Interval::new(start, end).unwrap()
+}
+
+fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+    intervals.iter().map(|&(start, end)| interval(start, end)).collect()
+}
+
+fn bounds(set: &IntervalSet) -> Vec<(i64, i64)> {
+    set.intervals().iter().map(|&interval| interval.into()).collect()
+}
+
+#[test]
+fn intervals_include_both_bounds() {
+    let sections = interval(2, 6);
+    assert_eq!((sections.len(), sections.to_string()), (5, "2-6".to_string()));
+    assert!(sections.contains(2) && sections.contains(6) && !sections.contains(7));
+    assert!(sections.covers(interval(3, 6)) && !sections.covers(interval(3, 7)));
+    assert!(sections.overlaps(interval(6, 8)) && !sections.overlaps(interval(7, 8)));
+    assert_eq!(sections.intersection(interval(5, 9)), Some(interval(5, 6)));
+    assert_eq!(sections.intersection(interval(7, 9)), None);
+    assert_eq!(Interval::new(3, 2), None);
+    assert_eq!(Interval::point(4).len(), 1);
+    assert_eq!(interval(i64::MIN, i64::MAX).len(), u64::MAX);
+
+    assert_eq!(serde_json::to_string(&sections).unwrap(), "[2,6]");
+    assert!(serde_json::from_str::<Interval>("[6,2]").unwrap_err().to_string().contains("ends before it starts"));
+}
+
+#[test]
+fn sets_merge_overlapping_and_touching_intervals() {
+    let mut sections = set(&[(1, 3), (10, 12), (6, 7)]);
+    assert_eq!(bounds(&sections), [(1, 3), (6, 7), (10, 12)]);
+    sections.insert(interval(4, 5));
+    assert_eq!(bounds(&sections), [(1, 7), (10, 12)]);
+    sections.insert(interval(0, 20));
+    assert_eq!(bounds(&sections), [(0, 20)]);
+
+    let edges = set(&[(i64::MAX, i64::MAX), (i64::MIN, i64::MIN)]);
+    assert_eq!(edges.len(), 2);
+    assert_eq!(edges.gaps().next(), Some(interval(i64::MIN + 1, i64::MAX - 1)));
+}
+
+#[test]
+fn set_operations() {
+    let a = set(&[(1, 5), (10, 15)]);
+    let b = set(&[(4, 11), (14, 20)]);
+    assert_eq!(bounds(&a.union(&b)), [(1, 20)]);
+    assert_eq!(bounds(&a.intersection(&b)), [(4, 5), (10, 11), (14, 15)]);
+    assert_eq!(bounds(&a.difference(&b)), [(1, 3), (12, 13)]);
+    assert_eq!(bounds(&b.difference(&a)), [(6, 9), (16, 20)]);
+    assert!(a.difference(&a).is_empty());
+    assert_eq!(a.difference(&IntervalSet::new()), a);
+
+    assert_eq!((a.len(), a.gaps().collect::<Vec<_>>()), (11, vec![interval(6, 9)]));
+    assert!(a.contains(10) && !a.contains(7));
+    assert!(a.covers(interval(11, 15)) && !a.covers(interval(4, 10)));
+}
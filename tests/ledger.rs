@@ -0,0 +1,55 @@
+use std::path::PathBuf;
+
+use aoc::ledger::{sha256_hex, Check, Ledger};
+
+const DAY2_TEST: &str = "A Y\nB X\nC Z\n";
+
+fn temp_ledger(name: &str) -> (Ledger, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("aoc-ledger-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    (Ledger::open(&dir), dir)
+}
+
+#[test]
+fn hashes_with_sha256() {
+    assert_eq!(
+        sha256_hex(b"abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn detects_changed_answers() {
+    let (ledger, dir) = temp_ledger("record");
+
+    assert_eq!(ledger.record(2022, 2, 1, DAY2_TEST, "15").unwrap(), Check::New);
+    assert_eq!(ledger.record(2022, 2, 1, DAY2_TEST, "15").unwrap(), Check::Unchanged);
+    assert_eq!(ledger.record(2022, 2, 2, DAY2_TEST, "12").unwrap(), Check::New);
+    assert_eq!(
+        ledger.record(2022, 2, 1, DAY2_TEST, "16").unwrap(),
+        Check::Changed { previous: "15".to_string() }
+    );
+
+    // unchanged answers are not appended again
+    assert_eq!(ledger.entries().unwrap().len(), 3);
+    assert_eq!(ledger.input(&sha256_hex(DAY2_TEST.as_bytes())).unwrap(), DAY2_TEST);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[test]
+fn verify_replays_the_latest_answers() {
+    let (ledger, dir) = temp_ledger("verify");
+
+    ledger.record(2022, 2, 1, DAY2_TEST, "14").unwrap();
+    ledger.record(2022, 2, 1, DAY2_TEST, "15").unwrap();
+    ledger.record(2022, 2, 2, DAY2_TEST, "12\tand\nmore").unwrap();
+
+    let mismatches = ledger.verify().unwrap();
+    assert_eq!(mismatches.len(), 1);
+    assert_eq!(mismatches[0].entry.part, 2);
+    assert_eq!(mismatches[0].entry.answer, "12\tand\nmore");
+    assert_eq!(mismatches[0].actual, Ok("12".to_string()));
+
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,62 @@
+use aoc::util::lenient::{self, Action};
+use aoc::y2022::{day01, day02, day04, day08, day09, day13, day14};
+
+mod common;
+
+use common::lines;
+
+#[test]
+fn malformed_lines_are_skipped_with_their_line_numbers() {
+    let (rounds, warnings) = lenient::run(|| day02::parse(&lines("A Y\nB\nC Z")));
+    assert_eq!(rounds.unwrap().len(), 2);
+    assert_eq!((warnings[0].line, warnings[0].action), (2, Action::Skipped));
+
+    let (pairs, warnings) = lenient::run(|| day04::parse(&lines("2-4,6-8\n2-3 4-5\n5-7,7-9")));
+    assert_eq!(day04::part1(&pairs.unwrap()), 0);
+    assert_eq!(warnings.len(), 1);
+
+    let (motions, warnings) = lenient::run(|| day09::parse(&lines("R 4\nU x\nL 3")));
+    assert_eq!(motions.unwrap().len(), 2);
+    assert_eq!(warnings[0].to_string(), "line 2: skipped: 'x' is not a distance: invalid digit found in string");
+}
+
+#[test]
+fn warnings_name_the_line_once() {
+    let (elves, warnings) = lenient::run(|| day01::parse(&lines("1\nx\n\n2")));
+    assert_eq!(elves.unwrap().len(), 2);
+    assert_eq!(warnings[0].to_string(), "line 2: skipped: 'x' is not a calorie count: invalid digit found in string");
+
+    let (forest, warnings) = lenient::run(|| day08::parse(&lines("123\n4x6\n789")));
+    assert_eq!(forest.unwrap().height(), 2);
+    assert!(warnings[0].to_string().starts_with("line 2: skipped: "), "{}", warnings[0]);
+    assert_eq!(warnings[0].to_string().matches("line").count(), 1, "{}", warnings[0]);
+
+    let (paths, warnings) = lenient::run(|| day14::parse(&lines("498,4 -> 498,6\n\nfoo\n503,4 -> 502,4")));
+    assert_eq!(paths.unwrap().len(), 2);
+    assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), [3]);
+}
+
+#[test]
+fn sloppy_lines_are_repaired() {
+    let (rounds, warnings) = lenient::run(|| day02::parse(&lines("a   y\nC Z")));
+    assert_eq!(rounds.unwrap(), day02::parse(&lines("A Y\nC Z")).unwrap());
+    assert_eq!((warnings[0].line, warnings[0].action), (1, Action::Repaired));
+    assert_eq!(lenient::summary(&warnings), "0 lines skipped, 1 repaired");
+}
+
+#[test]
+fn a_pair_missing_a_packet_is_dropped() {
+    let (pairs, warnings) = lenient::run(|| day13::parse(&lines("[1]\n[2]\n\n[3\n[4]\n\n[5]\n[6]")));
+    assert_eq!(pairs.unwrap().len(), 2);
+    assert_eq!(warnings.iter().map(|w| w.line).collect::<Vec<_>>(), [4, 4]);
+    assert_eq!(lenient::summary(&warnings), "2 lines skipped, 0 repaired");
+}
+
+#[test]
+fn parsing_is_strict_outside_a_lenient_run() {
+    assert!(day04::parse(&lines("2-3 4-5")).is_err());
+    let ((), warnings) = lenient::run(|| assert!(lenient::is_lenient()));
+    assert!(warnings.is_empty());
+    assert!(!lenient::is_lenient());
+    assert!(day09::parse(&lines("U x")).is_err());
+}
@@ -0,0 +1,58 @@
+use aoc::y2022::{day01, day07, day11};
+
+mod common;
+
+use common::lines;
+
+#[test]
+fn calories_beyond_i32_are_summed() {
+    let input = lines("3000000000\n3000000000\n\n1\n\n2\n\n3\n\n");
+    assert_eq!(day01::part1(&day01::parse(&input).unwrap()), Ok(Some(6_000_000_000)));
+    assert_eq!(day01::part2(&day01::parse(&input).unwrap()), Ok(Some(6_000_000_005)));
+}
+
+#[test]
+fn calorie_overflow_is_an_error() {
+    let input = lines(&format!("{}\n1\n\n", u64::MAX));
+    let error = day01::part1(&day01::parse(&input).unwrap()).unwrap_err();
+    assert_eq!(error.to_string(), "arithmetic overflow while summing an Elf's calories");
+
+    let input = lines("99999999999999999999999\n");
+    assert!(day01::parse(&input).is_err());
+}
+
+#[test]
+fn full_disk_is_an_error() {
+    let input = lines("$ cd /\n$ ls\n80000000 big.bin\n");
+    let filesystem = day07::parse(&input).unwrap();
+    assert!(day07::part1(&filesystem).is_ok());
+    assert!(day07::part2(&filesystem).is_err());
+}
+
+#[test]
+fn directory_size_overflow_is_an_error() {
+    let input = lines(&format!("$ cd /\n$ ls\n{} a\n{} b\n", u64::MAX, u64::MAX));
+    assert!(day07::parse(&input).is_err());
+}
+
+#[test]
+fn worry_overflow_is_an_error() {
+    let input = lines(
+        "Monkey 0:
+  Starting items: 4294967296
+  Operation: new = old * old
+  Test: divisible by 2
+    If true: throw to monkey 1
+    If false: throw to monkey 1
+
+Monkey 1:
+  Starting items: 1
+  Operation: new = old + 1
+  Test: divisible by 3
+    If true: throw to monkey 0
+    If false: throw to monkey 0
+",
+    );
+    assert!(day11::part1(&day11::parse(&input).unwrap()).is_err());
+    assert!(day11::part2(&day11::parse(&input).unwrap()).is_ok());
+}
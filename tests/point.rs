@@ -0,0 +1,43 @@
+use aoc::util::grid::Grid;
+use aoc::util::point::{Direction, Direction8, Point};
+
+#[test]
+fn arithmetic() {
+    let a = Point::new(3, -2);
+    let b = Point::new(1, 4);
+    assert_eq!(a + b, Point::new(4, 2));
+    assert_eq!(a - b, Point::new(2, -6));
+    assert_eq!(-a, Point::new(-3, 2));
+    assert_eq!(a * 2, Point::new(6, -4));
+    assert_eq!((a - b).signum(), Point::new(1, -1));
+    assert_eq!(Point::ORIGIN.signum(), Point::ORIGIN);
+}
+
+#[test]
+fn distances() {
+    let a = Point::new(3, -2);
+    let b = Point::new(1, 4);
+    assert_eq!(a.manhattan(b), 8);
+    assert_eq!(a.chebyshev(b), 6);
+    assert_eq!(a.manhattan(a), 0);
+}
+
+#[test]
+fn directions() {
+    let total = Direction::ALL.iter().fold(Point::ORIGIN, |p, d| p + d.vector());
+    assert_eq!(total, Point::ORIGIN);
+    assert_eq!(Direction::from_char('U'), Some(Direction::Up));
+    assert_eq!(Direction::from_char('X'), None);
+    assert_eq!(Direction8::from(Direction::Left).vector(), Direction::Left.vector());
+    assert!(Direction8::ALL.iter().all(|d| d.vector().chebyshev(Point::ORIGIN) == 1));
+}
+
+#[test]
+fn grid_indices() {
+    let grid = Grid::parse(&["ab", "cd"], Ok).unwrap();
+    assert_eq!(Point::new(1, 0).to_pos(), Some((0, 1)));
+    assert_eq!(Point::new(-1, 0).to_pos(), None);
+    assert_eq!(Point::from((1, 0)), Point::new(0, 1));
+    assert_eq!(grid[Point::new(1, 0)], 'b');
+    assert_eq!(grid.get_point(Point::new(0, 2)), None);
+}
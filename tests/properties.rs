@@ -0,0 +1,132 @@
+//! Invariants of the day models, checked on many random inputs.
+
+use aoc::gen::{self, Size};
+use aoc::util::interval::Interval;
+use aoc::util::point::Direction;
+use aoc::util::rng::Rng;
+use aoc::y2022::{day01, day04, day05, day07, day09, day12};
+
+mod common;
+
+use common::lines;
+
+/**
+ * How many random inputs each property is checked on.
+ */
+const CASES: u64 = 64;
+
+/**
+ * Random inputs for a day from the input generators, with the seed that made
+ * each one so that a failure can be replayed with `aoc gen --seed`.
+ */
+fn generated(day: u8) -> impl Iterator<Item = (u64, Vec<String>)> {
+    (0..CASES).map(move |seed| {
+        let input = gen::generate(2022, day, &mut Rng::new(seed), Size::SMALL).unwrap();
+        (seed, lines(&input))
+    })
+}
+
+#[test]
+fn overlap_is_symmetric_and_implied_by_containment() {
+    let mut rng = Rng::new(4);
+    for _ in 0..CASES * 16 {
+        let mut sections = || {
+            let first = rng.range(1, 20);
+            Interval::new(first, first + rng.range(0, 10)).unwrap()
+        };
+        let (a, b) = (sections(), sections());
+        let (contains, overlaps) = (day04::part1(&[(a, b)]), day04::part2(&[(a, b)]));
+
+        assert_eq!(contains, day04::part1(&[(b, a)]), "{:?} {:?}", a, b);
+        assert_eq!(overlaps, day04::part2(&[(b, a)]), "{:?} {:?}", a, b);
+        assert!(contains <= overlaps, "{:?} {:?} contain without overlapping", a, b);
+    }
+}
+
+#[test]
+fn knots_touch_after_every_step() {
+    for (seed, input) in generated(9) {
+        let motions = day09::parse(&input).unwrap();
+        let mut rope = day09::Rope::new(10);
+        for motion in &motions {
+            for _ in 0..motion.steps {
+                rope.step(motion.direction);
+                for pair in rope.knots.windows(2) {
+                    assert!(pair[0].chebyshev(pair[1]) <= 1, "seed {}: {:?}", seed, rope.knots);
+                }
+            }
+        }
+    }
+
+    // a single knot is its own tail
+    let mut rope = day09::Rope::new(1);
+    rope.step(Direction::Up);
+    assert_eq!(rope.tail(), rope.knots[0]);
+}
+
+#[test]
+fn moves_conserve_crates() {
+    for (seed, input) in generated(5) {
+        let crates = day05::parse(&input).unwrap();
+        let total: usize = crates.stacks.iter().map(|s| s.len()).sum();
+        for in_batches in [false, true] {
+            let mut stacks = crates.stacks.clone();
+            for m in &crates.moves {
+                day05::rearrange(&mut stacks, m, in_batches).unwrap();
+                assert_eq!(stacks.iter().map(|s| s.len()).sum::<usize>(), total, "seed {}", seed);
+            }
+        }
+    }
+}
+
+#[test]
+fn directory_sizes_add_up() {
+    for (seed, input) in generated(7) {
+        let filesystem = day07::parse(&input).unwrap();
+        for dir in filesystem.root.walk() {
+            let files: u64 = dir.files.values().sum();
+            let subdirs: u64 = dir.dirs.values().map(|d| d.size().unwrap()).sum();
+            assert_eq!(dir.size().unwrap(), files + subdirs, "seed {}", seed);
+        }
+
+        let all_files: u64 = filesystem.root.walk().iter().flat_map(|d| d.files.values()).sum();
+        assert_eq!(filesystem.root.size().unwrap(), all_files, "seed {}", seed);
+    }
+}
+
+#[test]
+fn distances_grow_by_at_most_one_per_step() {
+    for (seed, input) in generated(12) {
+        let heightmap = day12::parse(&input).unwrap();
+        let distances = day12::distances(&heightmap);
+        let heights = &heightmap.heights;
+        assert_eq!(distances[heightmap.start], Some(0), "seed {}", seed);
+
+        for (pos, &height) in heights.iter() {
+            let Some(distance) = distances[pos] else {
+                continue;
+            };
+            for next in heights.neighbours4(pos) {
+                // every position one climbable step away is at most one step further
+                if heights[next] <= height + 1 {
+                    let next_distance = distances[next].unwrap();
+                    assert!(next_distance <= distance + 1, "seed {}: {:?} -> {:?}", seed, pos, next);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn top_three_carry_at_least_the_top_one() {
+    for (seed, input) in generated(1) {
+        let elves = day01::parse(&input).unwrap();
+        let (top, top_three) = (day01::part1(&elves).unwrap().unwrap(), day01::part2(&elves).unwrap());
+        let Some(top_three) = top_three else {
+            assert!(elves.len() < 3, "seed {}", seed);
+            continue;
+        };
+        assert!(top <= top_three, "seed {}", seed);
+        assert!(top_three <= 3 * top, "seed {}", seed);
+    }
+}
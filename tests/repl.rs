@@ -0,0 +1,59 @@
+use aoc::registry;
+use aoc::repl::{Repl, Reply};
+
+mod common;
+
+use common::example;
+
+fn repl(day: u8) -> Repl {
+    (registry::querier_for(2022, day).unwrap().start)(&example(day)).unwrap()
+}
+
+fn output(repl: &Repl, line: &str) -> String {
+    match repl.eval(line) {
+        Ok(Reply::Output(output)) => output,
+        other => panic!("{:?} gave {:?}", line, other),
+    }
+}
+
+#[test]
+fn queries_answer_about_the_example() {
+    let filesystem = repl(7);
+    assert_eq!(output(&filesystem, "larger 10000000"), "  48381165 /\n  24933642 /d/\n2 directories");
+    assert_eq!(output(&filesystem, "size /a/e"), "584");
+
+    let crates = repl(5);
+    assert_eq!(output(&crates, "stack 1"), "[Z] [N]");
+    assert_eq!(output(&crates, "stack 1 1"), "[Z] [N] [D]");
+    assert_eq!(output(&crates, "stack 1 2"), "(empty)");
+    assert_eq!(output(&crates, "tops"), "CMZ");
+
+    let monkeys = repl(11);
+    assert_eq!(output(&monkeys, "items 0 1"), "Monkey 0: 20, 23, 27, 26");
+
+    let heightmap = repl(12);
+    assert_eq!(output(&heightmap, "distance 2 5"), "31 steps from the start");
+    assert_eq!(output(&heightmap, "  distance  0 0 "), "0 steps from the start");
+}
+
+#[test]
+fn command_names_are_completed() {
+    let filesystem = repl(7);
+    assert_eq!(filesystem.complete("l"), ["larger", "ls"]);
+    assert_eq!(filesystem.complete("q"), ["quit"]);
+    assert!(filesystem.complete("x").is_empty());
+    assert_eq!(filesystem.names().count(), 5);
+}
+
+#[test]
+fn bad_command_lines_are_reported() {
+    let crates = repl(5);
+    assert_eq!(crates.eval(""), Ok(Reply::Output(String::new())));
+    assert_eq!(crates.eval("quit"), Ok(Reply::Quit));
+    assert!(crates.eval("stack").unwrap_err().contains("missing stack"));
+    assert_eq!(crates.eval("stack x"), Err("'x' is not a valid stack".to_string()));
+    assert_eq!(crates.eval("stack 1 2 3"), Err("usage: stack N [MOVES]".to_string()));
+    assert_eq!(crates.eval("tops 9"), Err("there are only 4 moves".to_string()));
+    assert!(crates.eval("unstack").unwrap_err().starts_with("unknown command"));
+    assert!(registry::querier_for(2022, 3).is_none());
+}
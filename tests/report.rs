@@ -0,0 +1,63 @@
+use std::time::Duration;
+
+use aoc::report::{self, Options};
+
+mod common;
+
+use common::example_text;
+
+#[test]
+fn day_report_holds_answers_stats_and_pictures() {
+    let report = report::day(2022, 12, &example_text(12), None);
+    assert_eq!(report.parts.len(), 2);
+    assert_eq!(report.parts[0].answer, Ok("31".to_string()));
+    assert!(report.stats.contains(&("Grid".to_string(), "8 × 5".to_string())));
+    assert_eq!(report.pictures.len(), 1);
+    // one arrow per step of the 31 step path, several paths are that short
+    let picture = &report.pictures[0].text;
+    assert_eq!(picture.lines().count(), 5);
+    assert_eq!(picture.chars().filter(|c| "<>^v".contains(*c)).count(), 31);
+    assert!(picture.starts_with('v') && picture.contains('E'));
+    assert!(report.error.is_none());
+}
+
+#[test]
+fn multi_line_answers_are_pictures() {
+    let reports = vec![report::day(2022, 10, &example_text(10), None)];
+    let markdown = report::to_markdown(2022, &reports);
+    assert!(markdown.contains("| 2 | see below |"), "{}", markdown);
+    assert!(markdown.contains("```text\n##..##..##..##..##..##..##..##..##..##..\n"), "{}", markdown);
+
+    let html = report::to_html(2022, &reports);
+    assert!(html.contains("<pre>##..##..##..##..##..##..##..##..##..##..\n"), "{}", html);
+}
+
+#[test]
+fn html_is_escaped() {
+    let reports = vec![report::day(2022, 14, &example_text(14), None)];
+    let html = report::to_html(2022, &reports);
+    assert!(html.contains("<h3>Cave once sand falls into the abyss</h3>"));
+    assert!(!html.contains("<script"));
+
+    let reports = vec![report::day(2022, 13, "[1,<b>]\n[2]\n", None)];
+    let html = report::to_html(2022, &reports);
+    assert!(html.contains("&lt;b&gt;"), "{}", html);
+    assert!(!html.contains("<b>"), "{}", html);
+}
+
+#[test]
+fn pictures_are_drawn_within_the_timeout() {
+    let report = report::day(2022, 14, &example_text(14), Some(Duration::ZERO));
+    assert!(report.parts.iter().all(|part| part.answer == Err("solver timed out".to_string())));
+    assert_eq!(report.pictures.len(), 2);
+    assert!(report.pictures.iter().all(|picture| picture.text == "not drawn, solver timed out"));
+    assert!(report.stats.contains(&("Rock paths".to_string(), "2".to_string())));
+}
+
+#[test]
+fn missing_inputs_are_reported() {
+    let root = std::env::temp_dir().join("aoc-report-test-missing");
+    let reports = report::calendar(2022, &Options { root: &root, timeout: None });
+    assert_eq!(reports.len(), 15);
+    assert!(reports.iter().all(|r| r.parts.is_empty() && r.error.is_some()));
+}
@@ -0,0 +1,34 @@
+use aoc::scan;
+use aoc::util::scan::ScanError;
+
+#[test]
+fn captures_typed_values() {
+    let (num, src, dst): (u32, usize, usize) = scan!("move 13 from 2 to 9", "move {} from {} to {}").unwrap();
+    assert_eq!((num, src, dst), (13, 2, 9));
+
+    let operand: i32 = scan!("addx -7", "addx {}").unwrap();
+    assert_eq!(operand, -7);
+
+    let (a, op, b): (&str, char, String) = scan!("new = old * 19", "new = {} {} {}").unwrap();
+    assert_eq!((a, op, b.as_str()), ("old", '*', "19"));
+}
+
+#[test]
+fn describes_mismatches() {
+    let err: ScanError = scan!("move 1 frm 2 to 3", "move {} from {} to {}")
+        .map(|_: (u32, u32, u32)| ())
+        .unwrap_err();
+    assert!(err.reason.contains("expected \" from \""), "{}", err);
+
+    let err = scan!("addx ten", "addx {}").map(|_: i32| ()).unwrap_err();
+    assert!(err.reason.contains("\"ten\") is not a valid i32"), "{}", err);
+
+    let err = scan!("noop", "addx {}").map(|_: i32| ()).unwrap_err();
+    assert!(err.reason.contains("column 1"), "{}", err);
+
+    let err = scan!("move 1 from 2 to 3", "move {} from {} to {}").map(|_: (u32, u32)| ()).unwrap_err();
+    assert!(err.reason.contains("3 placeholder(s) but 2"), "{}", err);
+
+    let err = scan!("Monkey 3: extra", "Monkey {}:").map(|_: u32| ()).unwrap_err();
+    assert!(err.reason.contains("trailing"), "{}", err);
+}
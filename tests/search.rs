@@ -0,0 +1,68 @@
+use aoc::util::grid::Grid;
+use aoc::util::search;
+
+/**
+ * A weighted maze, `#` a wall and every digit the cost of stepping onto it.
+ */
+fn maze() -> Grid<char> {
+    Grid::parse(&["1191", "1#91", "1#11", "1111"], Ok).unwrap()
+}
+
+fn steps(maze: &Grid<char>, pos: (usize, usize)) -> Vec<((usize, usize), u64)> {
+    maze.neighbours4(pos)
+        .filter_map(|next| maze[next].to_digit(10).map(|cost| (next, cost as u64)))
+        .collect()
+}
+
+#[test]
+fn breadth_first_search_counts_steps_from_the_nearest_start() {
+    let line = |&n: &i32| [n - 1, n + 1].into_iter().filter(|n| (0..=10).contains(n));
+    let search = search::bfs([0, 10], line, |_| false);
+    assert_eq!(search.distances.len(), 11);
+    assert_eq!(search.distance(&3), Some(3));
+    assert_eq!(search.distance(&8), Some(2));
+    assert_eq!(search.path(&8), Some(vec![10, 9, 8]));
+    assert_eq!(search.goal, None);
+
+    // stopping at the goal leaves the far states unexplored
+    let search = search::bfs([0], line, |&n| n == 2);
+    assert_eq!((search.goal, search.goal_distance()), (Some(2), Some(2)));
+    assert_eq!(search.distance(&9), None);
+    assert_eq!(search.path(&9), None);
+
+    // a state queued but never expanded still has its distance
+    let search = search::bfs([5], line, |&n| n == 4);
+    assert_eq!(search.distance(&6), Some(1));
+    assert_eq!(search.distance(&7), None);
+}
+
+#[test]
+fn dijkstra_takes_the_cheapest_path() {
+    let maze = maze();
+    let search = search::dijkstra([(0, 0)], |&pos| steps(&maze, pos), |&pos| pos == (0, 3));
+    // around the wall rather than over the nines
+    assert_eq!(search.goal_distance(), Some(9));
+    let path = search.path(&(0, 3)).unwrap();
+    assert_eq!(path.len(), 10);
+    assert_eq!((path[0], path[3]), ((0, 0), (3, 0)));
+
+    let everything = search::dijkstra([(0, 0)], |&pos| steps(&maze, pos), |_| false);
+    assert_eq!(everything.distances.len(), 14);
+    assert_eq!(everything.distance(&(1, 1)), None);
+}
+
+#[test]
+fn a_star_agrees_with_dijkstra() {
+    let maze = maze();
+    let goal = (0, 3);
+    let manhattan = |&(row, col): &(usize, usize)| (row.abs_diff(goal.0) + col.abs_diff(goal.1)) as u64;
+    let guided = search::astar([(3, 3), (1, 0)], |&pos| steps(&maze, pos), manhattan, |&pos| pos == goal);
+    let plain = search::dijkstra([(3, 3), (1, 0)], |&pos| steps(&maze, pos), |&pos| pos == goal);
+    assert_eq!(guided.goal_distance(), Some(3));
+    assert_eq!(guided.goal_distance(), plain.goal_distance());
+    assert_eq!(guided.path(&goal).unwrap()[0], (3, 3));
+    assert!(guided.distances.len() <= plain.distances.len());
+
+    let walled_in = search::astar([(0, 0)], |_: &(usize, usize)| Vec::new(), manhattan, |&pos| pos == goal);
+    assert_eq!(walled_in.goal, None);
+}
@@ -0,0 +1,52 @@
+use std::path::Path;
+
+use aoc::selftest::{self, Example};
+
+fn root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+}
+
+#[test]
+fn every_solver_passes_on_its_example() {
+    let examples = selftest::load(root());
+    assert_eq!(examples.len(), 15);
+    for example in &examples {
+        // part 1 of day 15 asks about a row the example does not reach
+        let parts = if example.day == 15 { 1 } else { 2 };
+        assert_eq!(example.answers.len(), parts, "day {} is missing expected answers", example.day);
+    }
+
+    let failed: Vec<String> = selftest::run(&examples, None)
+        .iter()
+        .filter(|check| !check.passed())
+        .map(|check| format!("day {} part {} {}: {:?}", check.solution.day, check.solution.part, check.solution.variant, check.actual))
+        .collect();
+    assert!(failed.is_empty(), "{:#?}", failed);
+}
+
+#[test]
+fn wrong_answers_and_errors_fail() {
+    let example = Example {
+        year: 2022,
+        day: 2,
+        input: "A Y\nB X\nC Z\n".to_string(),
+        answers: vec![(1, "15".to_string()), (2, "13".to_string())],
+    };
+    let checks = selftest::run(std::slice::from_ref(&example), None);
+    assert_eq!(checks.iter().map(|c| c.passed()).collect::<Vec<_>>(), [true, false]);
+
+    let broken = Example { input: "A W\n".to_string(), ..example };
+    assert!(selftest::run(&[broken], None).iter().all(|c| c.actual.is_err() && !c.passed()));
+}
+
+#[cfg(feature = "embed-examples")]
+#[test]
+fn embedded_examples_match_the_source_tree() {
+    assert_eq!(selftest::embedded().unwrap(), selftest::load(root()));
+}
+
+#[cfg(not(feature = "embed-examples"))]
+#[test]
+fn nothing_is_embedded_by_default() {
+    assert!(selftest::embedded().is_none());
+}
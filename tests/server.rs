@@ -0,0 +1,77 @@
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+
+use aoc::server;
+
+mod common;
+
+use common::example_text;
+
+const DAY2_TEST: &str = "A Y\nB X\nC Z\n";
+
+/**
+ * Starts a server on an ephemeral port and returns its address.
+ */
+fn start_server() -> String {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    thread::spawn(move || server::serve(listener, server::DEFAULT_TIMEOUT, |e| panic!("{}", e)));
+    addr
+}
+
+fn request(addr: &str, method: &str, path: &str, body: &str) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+        method,
+        path,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let status = response[9..12].parse().unwrap();
+    let body = response.split_once("\r\n\r\n").unwrap().1.to_string();
+    (status, body)
+}
+
+#[test]
+fn solves_over_http() {
+    let addr = start_server();
+    let (status, body) = request(&addr, "POST", "/2022/day/2/part/2", DAY2_TEST);
+    assert_eq!(status, 200);
+    assert!(body.contains("\"answer\":\"12\""), "{}", body);
+    assert!(body.contains("\"time_us\":"), "{}", body);
+}
+
+#[test]
+fn reports_unknown_routes_and_methods() {
+    let addr = start_server();
+    assert_eq!(request(&addr, "POST", "/2022/day/25/part/1", "").0, 404);
+    assert_eq!(request(&addr, "POST", "/nowhere", "").0, 404);
+    assert_eq!(request(&addr, "GET", "/2022/day/2/part/1", "").0, 405);
+}
+
+#[test]
+fn reports_solver_failures_as_errors() {
+    let response = server::handle("POST", "/2022/day/3/part/2", "only one line");
+    assert_eq!(response.status, 422);
+    assert!(response.body.contains("\"error\":"), "{}", response.body);
+}
+
+#[test]
+fn answers_are_json() {
+    let response = server::handle("POST", "/2022/day/10/part/2", &example_text(10));
+    let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+    assert_eq!(body["day"], 10);
+    assert!(body["answer"].as_str().unwrap().starts_with("##..##..##..##..##..##..##..##..##..##..\n"));
+    assert!(body.get("error").is_none());
+
+    let response = server::handle("POST", "/2022/day/1/part/1", "\"quoted\"\n");
+    let body: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+    assert!(body["error"].as_str().unwrap().contains("'\"quoted\"'"), "{}", body);
+    assert!(body.get("answer").is_none());
+}
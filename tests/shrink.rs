@@ -0,0 +1,90 @@
+use std::path::Path;
+use std::time::Duration;
+
+use aoc::registry::{self, Solution};
+use aoc::runner;
+use aoc::shrink::{self, Property};
+
+mod common;
+
+use common::lines;
+
+#[test]
+fn lines_are_removed_while_the_failure_persists() {
+    let input: Vec<u32> = (0..100).collect();
+    let minimal = shrink::ddmin(input, &mut |pieces: &[u32]| pieces.contains(&17) && pieces.contains(&81));
+    assert_eq!(minimal, vec![17, 81]);
+}
+
+#[test]
+fn blocks_and_characters_are_removed() {
+    let input = lines("Monkey 0:\n  a\n\nMonkey 1:\n  bad\n\nMonkey 2:\n  c\n");
+    let minimal = shrink::minimize(&input, |candidate| candidate.iter().any(|line| line.contains("bad")));
+    assert_eq!(minimal, vec!["bad"]);
+
+    let input = lines("abcdefghijklmnopqrstuvwxyz");
+    let minimal = shrink::minimize(&input, |candidate| candidate[0].contains('z') && candidate[0].contains('q'));
+    assert_eq!(minimal, vec!["qz"]);
+}
+
+static FRAGILE: Solution = Solution {
+    year: 2022,
+    day: 1,
+    part: 1,
+    variant: "fragile",
+    solve: |input| {
+        if input.iter().any(|line| line == "3") {
+            panic!("cannot handle a 3");
+        }
+        Ok(input.len().to_string())
+    },
+};
+
+#[test]
+fn panicking_inputs_shrink_to_the_culprit() {
+    let property = Property::Panic(&FRAGILE);
+    let timeout = Duration::from_secs(1);
+    let input = lines("1\n2\n\n3\n4\n\n5\n");
+    assert!(property.fails(&input, timeout));
+
+    let minimal = shrink::minimize(&input, |candidate| property.fails(candidate, timeout));
+    assert_eq!(minimal, vec!["3"]);
+}
+
+#[test]
+fn cases_are_saved_by_content() {
+    let root = std::env::temp_dir().join(format!("aoc-shrink-test-{}", std::process::id()));
+    let first = shrink::save_case(&root, 2022, 6, &lines("y")).unwrap();
+    let second = shrink::save_case(&root, 2022, 6, &lines("y")).unwrap();
+    assert_eq!(first, second);
+    assert!(first.starts_with(root.join("2022/day06/cases")));
+    assert_eq!(std::fs::read_to_string(&first).unwrap(), "y\n");
+    std::fs::remove_dir_all(root).unwrap();
+}
+
+#[test]
+fn saved_cases_no_longer_fail() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    for solution in registry::all().filter(|s| s.year == 2022) {
+        let dir = shrink::case_path(root, 2022, solution.day, "").parent().unwrap().to_path_buf();
+        let Ok(entries) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in entries {
+            let path = entry.unwrap().path();
+            let input = lines(&std::fs::read_to_string(&path).unwrap());
+            let variants: Vec<_> = registry::variants(2022, solution.day, solution.part).collect();
+            let check = runner::cross_check(&variants, &input, Some(shrink::DEFAULT_TIMEOUT));
+            assert!(check.agrees(), "{} part {}", path.display(), solution.part);
+            for (variant, outcome) in check.outcomes {
+                assert!(
+                    !outcome.answer.is_err_and(|f| f.is_panic()),
+                    "{} part {} variant {} panics",
+                    path.display(),
+                    solution.part,
+                    variant.variant
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use aoc::registry;
+use aoc::sim::{Checkpoint, Session, Simulation};
+use aoc::y2022::{day10, day11, day14};
+
+mod common;
+
+use common::example;
+
+fn session(day: u8, part: u8) -> Session {
+    let simulator = registry::simulator_for(2022, day, part).unwrap();
+    (simulator.start)(&example(day)).unwrap()
+}
+
+#[test]
+fn simulations_step_to_the_answer() {
+    let monkeys = day11::parse(&example(11)).unwrap();
+    let mut simulation = day11::part1_simulation(&monkeys);
+    simulation.step().unwrap();
+    assert_eq!(simulation.state().items[0], [20, 23, 27, 26]);
+    simulation.run().unwrap();
+    assert_eq!(simulation.state().round, 20);
+    assert_eq!(simulation.state().inspects, [101, 95, 7, 105]);
+    assert!(!simulation.step().unwrap());
+
+    let program = day10::parse(&example(10)).unwrap();
+    let mut cpu = day10::simulation(&program);
+    for _ in 0..20 {
+        cpu.step().unwrap();
+    }
+    assert_eq!(cpu.state().x, 21);
+    assert_eq!(cpu.state().signal_strength, 420);
+}
+
+#[test]
+fn going_back_replays_the_same_states() {
+    let mut session = session(14, 2);
+    session.run(Some(10)).unwrap();
+    let after_ten = session.render();
+    session.run(Some(5)).unwrap();
+
+    assert_eq!(session.back(5), Ok(5));
+    assert_eq!(session.steps(), 10);
+    assert_eq!(session.render(), after_ten);
+
+    // not before the start
+    assert_eq!(session.back(100), Ok(10));
+    assert_eq!(session.steps(), 0);
+    assert_eq!(session.run(None), Ok(93));
+    assert!(session.is_finished());
+}
+
+#[test]
+fn checkpoints_resume_where_they_were_taken() {
+    let mut session = session(9, 2);
+    session.run(Some(7)).unwrap();
+    let checkpoint = session.checkpoint().unwrap();
+
+    let path = std::env::temp_dir().join(format!("aoc-sim-test-{}.json", std::process::id()));
+    checkpoint.save(&path).unwrap();
+    let loaded = Checkpoint::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, checkpoint);
+
+    let mut resumed = self::session(9, 2);
+    resumed.resume(loaded).unwrap();
+    assert_eq!((resumed.steps(), resumed.render()), (7, session.render()));
+    resumed.run(None).unwrap();
+    session.run(None).unwrap();
+    assert_eq!((resumed.steps(), resumed.render()), (session.steps(), session.render()));
+
+    // a checkpoint of another part or input is refused
+    let error = self::session(9, 1).resume(checkpoint.clone()).unwrap_err();
+    assert!(error.contains("part 2"), "{}", error);
+    let mut other = (registry::simulator_for(2022, 9, 2).unwrap().start)(&["R 4".to_string()]).unwrap();
+    assert!(other.resume(checkpoint).is_err());
+}
+
+#[test]
+fn sand_is_rendered_as_it_rests() {
+    let paths = day14::parse(&example(14)).unwrap();
+    let mut simulation = day14::part1_simulation(&paths);
+    for _ in 0..5 {
+        simulation.step().unwrap();
+    }
+    // the puzzle's picture, which is one column narrower on both sides
+    let expected = "\
+......+...
+..........
+..........
+..........
+....#...##
+....#...#.
+..###...#.
+......o.#.
+....oooo#.
+#########.";
+    let rendered = simulation.render();
+    let cropped: Vec<&str> = rendered.lines().map(|line| &line[1..line.len() - 1]).collect();
+    assert_eq!(cropped.join("\n"), expected);
+}
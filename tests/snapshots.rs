@@ -0,0 +1,63 @@
+use std::path::Path;
+
+use aoc::registry;
+use aoc::snapshot::{self, Check};
+use aoc::util::input::puzzle_path;
+
+/**
+ * Compares every rendering of every example input with its stored snapshot.
+ * Run with `UPDATE_SNAPSHOTS=1` to accept intentional changes.
+ */
+#[test]
+fn renderings_match_snapshots() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let update = snapshot::update_from_env();
+
+    let mut days: Vec<u8> = registry::all().filter(|s| s.year == 2022).map(|s| s.day).collect();
+    days.dedup();
+
+    let mut failures = Vec::new();
+    for day in days {
+        let Ok(input) = std::fs::read_to_string(root.join(puzzle_path(2022, day, "test.txt"))) else {
+            continue;
+        };
+        for rendering in snapshot::renderings(2022, day, &input) {
+            let path = snapshot::path(root, 2022, day, &rendering.name);
+            match snapshot::check(&path, &rendering.text, update).unwrap() {
+                Check::Matches | Check::Updated => {}
+                Check::Missing => failures.push(format!("{}: missing", path.display())),
+                Check::Changed { diff } => failures.push(format!("{}: changed\n{}", path.display(), diff)),
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{}\nrerun with {}=1 if the changes are intended",
+        failures.join("\n"),
+        snapshot::UPDATE_ENV
+    );
+}
+
+#[test]
+fn diff_shows_changed_lines_with_context() {
+    let expected = "a\nb\nc\nd\ne\nf\ng\nh\n";
+    let actual = "a\nb\nc\nd\nE\nf\ng\nh\n";
+    assert_eq!(snapshot::diff(expected, actual), "  ...\n  c\n  d\n- e\n+ E\n  f\n  g\n  ...\n");
+    assert_eq!(snapshot::diff("x\n", "x\ny\n"), "  x\n+ y\n");
+}
+
+#[test]
+fn check_reports_and_updates_changes() {
+    let path = std::env::temp_dir().join(format!("aoc-snapshot-test-{}", std::process::id())).join("picture.txt");
+
+    assert_eq!(snapshot::check(&path, "#.\n.#", false).unwrap(), Check::Missing);
+    assert_eq!(snapshot::check(&path, "#.\n.#", true).unwrap(), Check::Updated);
+    assert_eq!(snapshot::check(&path, "#.\n.#", false).unwrap(), Check::Matches);
+    assert_eq!(
+        snapshot::check(&path, "#.\n##", false).unwrap(),
+        Check::Changed { diff: "  #.\n- .#\n+ ##\n".to_string() }
+    );
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
@@ -0,0 +1,76 @@
+use aoc::gen::{self, Size};
+use aoc::registry::{self, Solution};
+use aoc::runner;
+use aoc::util::rng::Rng;
+use aoc::y2022::day06;
+
+mod common;
+
+use common::{example, lines};
+
+#[test]
+fn every_part_has_one_default_variant() {
+    let defaults: Vec<_> = registry::all().collect();
+    assert!(defaults.iter().all(|s| s.variant == registry::DEFAULT_VARIANT));
+    assert!(registry::all_variants().count() > defaults.len());
+
+    let fast = registry::find_variant(2022, 6, 2, "fast").unwrap();
+    assert_eq!((fast.solve)(&lines("mjqjpqmgbljsphdztnvjfqwrcgsmlb")), Ok("19".to_string()));
+    assert!(registry::find_variant(2022, 9, 1, "fast").is_none());
+}
+
+#[test]
+fn variants_agree_on_examples_and_generated_inputs() {
+    let days: Vec<u8> = registry::all_variants()
+        .filter(|s| s.year == 2022 && s.variant != registry::DEFAULT_VARIANT)
+        .map(|s| s.day)
+        .collect();
+
+    for day in days {
+        let mut inputs = vec![example(day)];
+        for seed in 0..5 {
+            inputs.push(lines(&gen::generate(2022, day, &mut Rng::new(seed), Size::SMALL).unwrap()));
+        }
+        for part in [1, 2] {
+            let variants: Vec<_> = registry::variants(2022, day, part).collect();
+            for input in &inputs {
+                let check = runner::cross_check(&variants, input, None);
+                assert!(check.agrees(), "day {} part {} on {:?}", day, part, input);
+            }
+        }
+    }
+}
+
+#[test]
+fn markers_at_the_very_end_are_found() {
+    // only the last window is a marker, and a stream without any
+    for (text, expected) in [("aaaabcd", Some(7)), ("aaaaaaa", None)] {
+        let stream = day06::parse(&lines(text)).unwrap();
+        assert_eq!(day06::part1(&stream), expected, "{}", text);
+        assert_eq!(day06::part1_fast(&stream), expected, "{}", text);
+    }
+}
+
+static RIGHT: Solution = Solution {
+    year: 2022,
+    day: 1,
+    part: 1,
+    variant: "right",
+    solve: |_| Ok("1".to_string()),
+};
+
+static WRONG: Solution = Solution {
+    year: 2022,
+    day: 1,
+    part: 1,
+    variant: "wrong",
+    solve: |_| Ok("2".to_string()),
+};
+
+#[test]
+fn disagreement_is_reported() {
+    let check = runner::cross_check(&[&RIGHT, &WRONG], &[], None);
+    assert!(!check.agrees());
+    assert_eq!(check.outcomes[1].0.variant, "wrong");
+    assert!(runner::cross_check(&[&RIGHT, &RIGHT], &[], None).agrees());
+}
@@ -0,0 +1,54 @@
+use aoc::gen::{self, Size};
+use aoc::registry;
+use aoc::util::input::MappedInput;
+use aoc::util::rng::Rng;
+use aoc::y2022::day06;
+
+mod common;
+
+use common::lines;
+
+fn temp_file(name: &str, contents: &str) -> std::path::PathBuf {
+    let path = std::env::temp_dir().join(format!("aoc-{}-{}.txt", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn mapped_inputs_read_like_files() {
+    let path = temp_file("mapped", "1000\n2000\n\n3000\n");
+    let input = MappedInput::open(&path).unwrap();
+    assert_eq!(input.text(), Ok("1000\n2000\n\n3000\n"));
+    let solve = registry::zero_copy_for(2022, 1, 1).unwrap().solve;
+    assert_eq!(solve(input.text().unwrap()), Ok("3000".to_string()));
+    std::fs::remove_file(&path).unwrap();
+
+    let path = temp_file("empty", "");
+    assert_eq!(MappedInput::open(&path).unwrap().bytes(), b"");
+    std::fs::write(&path, [0xff, 0xfe]).unwrap();
+    assert!(MappedInput::open(&path).unwrap().text().unwrap_err().contains("UTF-8"));
+    std::fs::remove_file(&path).unwrap();
+    assert!(MappedInput::open(&path).is_err());
+}
+
+#[test]
+fn zero_copy_parts_agree_with_the_default_variant() {
+    for (day, part) in [(1, 1), (1, 2), (2, 1), (2, 2), (4, 1), (4, 2), (6, 1), (6, 2), (10, 1), (10, 2)] {
+        let zero_copy = registry::zero_copy_for(2022, day, part).unwrap();
+        let solution = registry::find(2022, day, part).unwrap();
+        for seed in 0..5 {
+            let text = gen::generate(2022, day, &mut Rng::new(seed), Size::SMALL).unwrap();
+            assert_eq!((zero_copy.solve)(&text), (solution.solve)(&lines(&text)), "day {} part {} seed {}", day, part, seed);
+        }
+    }
+    assert!(registry::zero_copy_for(2022, 3, 1).is_none());
+}
+
+#[test]
+fn the_datastream_is_borrowed() {
+    let text = "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n";
+    let stream = day06::parse_lines(text.lines()).unwrap();
+    assert_eq!(stream.as_ptr(), text.as_ptr());
+    assert_eq!((day06::part1(stream), day06::part2_fast(stream)), (Some(7), Some(19)));
+    assert_eq!(day06::parse_lines("abcdé".lines()), Err("the datastream is not ASCII".to_string()));
+}